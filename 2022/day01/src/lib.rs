@@ -0,0 +1,42 @@
+use util::parse::{self, ParseError};
+use util::Solution;
+
+pub struct Day01;
+
+/// Calories carried by each elf in ascending order.
+fn get_sorted_calories(input: &str) -> Result<Vec<u32>, ParseError> {
+    let mut calories = parse::parse_blocks(input, |block| {
+        let start = *block;
+        let items = block.lines(|line| line.integer::<u32>())?;
+        items
+            .into_iter()
+            .try_fold(0u32, u32::checked_add)
+            .ok_or_else(|| start.error("total calories are too large"))
+    })?;
+    calories.sort_unstable();
+    Ok(calories)
+}
+
+impl Solution for Day01 {
+    type Input = Vec<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        get_sorted_calories(input)
+    }
+
+    fn part_one(calories: &Self::Input) -> Self::PartOne {
+        calories.last().copied().unwrap_or_default()
+    }
+
+    fn part_two(calories: &Self::Input) -> Self::PartTwo {
+        calories.iter().rev().take(3).sum()
+    }
+}
+
+util::aoc_tests! {
+    Day01,
+    part1: example1 => "24000", input => "67622";
+    part2: example2 => "45000", input => "201491";
+}
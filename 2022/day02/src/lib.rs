@@ -0,0 +1,110 @@
+use util::parse::{self, ParseError};
+use util::Solution;
+
+pub struct Day02;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Shape {
+    Rock = 1,
+    Paper = 2,
+    Scissor = 3,
+}
+
+impl Shape {
+    fn beats(self, other: Self) -> bool {
+        match (self, other) {
+            (Shape::Rock, Shape::Paper) => false,
+            (Shape::Rock, Shape::Scissor) => true,
+            (Shape::Paper, Shape::Rock) => true,
+            (Shape::Paper, Shape::Scissor) => false,
+            (Shape::Scissor, Shape::Rock) => false,
+            (Shape::Scissor, Shape::Paper) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Letter of the second column, which is the shape to play in part one and the outcome of the
+/// round in part two.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Response {
+    X,
+    Y,
+    Z,
+}
+
+fn get_score(left: Shape, right: Shape) -> u32 {
+    if left == right {
+        3 + right as u32
+    } else if right.beats(left) {
+        6 + right as u32
+    } else {
+        right as u32
+    }
+}
+
+impl Solution for Day02 {
+    type Input = Vec<(Shape, Response)>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, |line| {
+            let left = line.choice(&[
+                ("A", Shape::Rock),
+                ("B", Shape::Paper),
+                ("C", Shape::Scissor),
+            ])?;
+            line.tag(" ")?;
+            let right =
+                line.choice(&[("X", Response::X), ("Y", Response::Y), ("Z", Response::Z)])?;
+            Ok((left, right))
+        })
+    }
+
+    fn part_one(rounds: &Self::Input) -> Self::PartOne {
+        rounds
+            .iter()
+            .map(|&(left, right)| {
+                let right = match right {
+                    Response::X => Shape::Rock,
+                    Response::Y => Shape::Paper,
+                    Response::Z => Shape::Scissor,
+                };
+                (left, right)
+            })
+            .map(|(left, right)| get_score(left, right))
+            .sum()
+    }
+
+    fn part_two(rounds: &Self::Input) -> Self::PartTwo {
+        rounds
+            .iter()
+            .map(|&(left, right)| {
+                (
+                    left,
+                    match right {
+                        Response::X => match left {
+                            Shape::Rock => Shape::Scissor,
+                            Shape::Paper => Shape::Rock,
+                            Shape::Scissor => Shape::Paper,
+                        },
+                        Response::Y => left,
+                        Response::Z => match left {
+                            Shape::Rock => Shape::Paper,
+                            Shape::Paper => Shape::Scissor,
+                            Shape::Scissor => Shape::Rock,
+                        },
+                    },
+                )
+            })
+            .map(|(left, right)| get_score(left, right))
+            .sum()
+    }
+}
+
+util::aoc_tests! {
+    Day02,
+    part1: example1 => "15", input => "10816";
+    part2: example2 => "12", input => "11657";
+}
@@ -0,0 +1,72 @@
+use std::collections::HashSet;
+
+use util::parse::{self, ParseError};
+use util::Solution;
+
+pub struct Day03;
+
+fn get_priority(item: char) -> i32 {
+    match item {
+        'a'..='z' => (item as i32) - ('a' as i32) + 1,
+        'A'..='Z' => (item as i32) - ('A' as i32) + 27,
+        _ => unreachable!(),
+    }
+}
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, |line| {
+            Ok(line.take_while(|c| c.is_ascii_alphabetic()).to_owned())
+        })
+    }
+
+    fn part_one(rucksacks: &Self::Input) -> Self::PartOne {
+        rucksacks
+            .iter()
+            .map(|line| {
+                let one = &line[..(line.len() / 2)];
+                let two = &line[(line.len() / 2)..];
+                (one, two)
+            })
+            .filter_map(|(one, two)| {
+                let chars = one.chars().collect::<HashSet<_>>();
+                two.chars().find(|ch| chars.contains(ch))
+            })
+            .map(get_priority)
+            .sum()
+    }
+
+    fn part_two(rucksacks: &Self::Input) -> Self::PartTwo {
+        rucksacks
+            .iter()
+            .fold(vec![vec![]], |mut acc, line| {
+                if let Some(list) = acc.last_mut() {
+                    if list.len() < 3 {
+                        list.push(line);
+                    } else {
+                        acc.push(vec![line]);
+                    }
+                }
+                acc
+            })
+            .into_iter()
+            .filter_map(|list| {
+                list.into_iter()
+                    .map(|line| line.chars().collect::<HashSet<_>>())
+                    .reduce(|a, b| a.intersection(&b).copied().collect())
+            })
+            .filter_map(|item| item.into_iter().next())
+            .map(get_priority)
+            .sum()
+    }
+}
+
+util::aoc_tests! {
+    Day03,
+    part1: example1 => "157", input => "8039";
+    part2: example2 => "70", input => "2510";
+}
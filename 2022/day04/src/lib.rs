@@ -0,0 +1,49 @@
+use util::interval::Interval;
+use util::parse::{self, Cursor, ParseError};
+use util::Solution;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<(Interval<i32>, Interval<i32>)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let parse_range = |line: &mut Cursor| {
+            let start = line.integer()?;
+            line.tag("-")?;
+            let end_position = *line;
+            Interval::inclusive(start, line.integer()?)
+                .ok_or_else(|| end_position.error("section ID is too large"))
+        };
+        parse::parse_lines(input, |line| {
+            let left = parse_range(line)?;
+            line.tag(",")?;
+            Ok((left, parse_range(line)?))
+        })
+    }
+
+    fn part_one(range_pairs: &Self::Input) -> Self::PartOne {
+        range_pairs
+            .iter()
+            .filter(|(left_range, right_range)| {
+                left_range.contains_interval(right_range)
+                    || right_range.contains_interval(left_range)
+            })
+            .count()
+    }
+
+    fn part_two(range_pairs: &Self::Input) -> Self::PartTwo {
+        range_pairs
+            .iter()
+            .filter(|(left_range, right_range)| left_range.overlaps(right_range))
+            .count()
+    }
+}
+
+util::aoc_tests! {
+    Day04,
+    part1: example1 => "2", input => "534";
+    part2: example2 => "4", input => "841";
+}
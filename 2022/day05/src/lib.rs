@@ -0,0 +1,156 @@
+use util::parse::{self, Cursor, ParseError};
+use util::Solution;
+
+pub struct Day05;
+
+type Move = (usize, usize, usize);
+
+#[derive(Debug, Clone)]
+pub struct Procedure {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+/// Line of the drawing of the stacks, with the crates from left to right or the stack numbers.
+#[derive(Debug)]
+enum StackRow {
+    Crates(Vec<Option<char>>),
+    Numbers(usize),
+}
+
+fn parse_stack_row(line: &mut Cursor) -> Result<StackRow, ParseError> {
+    let start = *line;
+    if line
+        .rest()
+        .trim_start()
+        .starts_with(|c: char| c.is_ascii_digit())
+    {
+        let numbers = line.integers::<usize>()?;
+        if !numbers.iter().copied().eq(1..=numbers.len()) {
+            return Err(start.error("expected stacks numbered from 1"));
+        }
+        return Ok(StackRow::Numbers(numbers.len()));
+    }
+    let crates = line.separated(" ", |cell| {
+        if cell.try_tag("   ") {
+            return Ok(None);
+        }
+        cell.tag("[")?;
+        let letter_start = *cell;
+        let letter = cell.take_while(|c| c.is_ascii_uppercase());
+        if letter.len() != 1 {
+            return Err(letter_start.error("expected crate letter"));
+        }
+        cell.tag("]")?;
+        Ok(letter.chars().next())
+    })?;
+    Ok(StackRow::Crates(crates))
+}
+
+fn parse_stacks(block: &mut Cursor) -> Result<Vec<Vec<char>>, ParseError> {
+    let end = *block;
+    let rows = block.lines(|line| Ok((*line, parse_stack_row(line)?)))?;
+    let Some(((numbers_start, numbers), crate_rows)) = rows.split_last() else {
+        return Err(end.error("expected stacks"));
+    };
+    let &StackRow::Numbers(count) = numbers else {
+        return Err(numbers_start.error("expected stack numbers"));
+    };
+    let mut stacks = vec![vec![]; count];
+    for (start, row) in crate_rows.iter().rev() {
+        let StackRow::Crates(crates) = row else {
+            return Err(start.error("expected crates"));
+        };
+        if crates.len() > count {
+            return Err(start.error(format!("expected at most {count} stacks")));
+        }
+        for (stack, letter) in stacks.iter_mut().zip(crates) {
+            stack.extend(*letter);
+        }
+    }
+    Ok(stacks)
+}
+
+/// Index of a stack given by its number, which has to be one of the `count` stacks.
+fn parse_stack_index(line: &mut Cursor, count: usize) -> Result<usize, ParseError> {
+    let start = *line;
+    let number = line.integer::<usize>()?;
+    if !(1..=count).contains(&number) {
+        return Err(start.error(format!("expected stack from 1 to {count}")));
+    }
+    Ok(number - 1)
+}
+
+/// Parses a move that takes at most as many crates as the stack holds, given its `heights`, and
+/// updates them.
+fn parse_move(line: &mut Cursor, heights: &mut [usize]) -> Result<Move, ParseError> {
+    line.tag("move ")?;
+    let count_start = *line;
+    let count = line.integer()?;
+    line.tag(" from ")?;
+    let from = parse_stack_index(line, heights.len())?;
+    line.tag(" to ")?;
+    let to = parse_stack_index(line, heights.len())?;
+    if count > heights[from] {
+        return Err(count_start.error(format!(
+            "stack {} only holds {} crates",
+            from + 1,
+            heights[from]
+        )));
+    }
+    heights[from] -= count;
+    heights[to] += count;
+    Ok((count, from, to))
+}
+
+fn parse_procedure(input: &str) -> Result<Procedure, ParseError> {
+    let mut blocks = parse::blocks(input);
+    let stacks = blocks.next_parsed(parse_stacks)?;
+    let mut heights = stacks.iter().map(Vec::len).collect::<Vec<_>>();
+    let moves = blocks.next_parsed(|block| block.lines(|line| parse_move(line, &mut heights)))?;
+    Ok(Procedure { stacks, moves })
+}
+
+/// Takes the top `count` crates off a stack, keeping their order.
+fn take_crates(stack: &mut Vec<char>, count: usize) -> Vec<char> {
+    stack.split_off(stack.len().saturating_sub(count))
+}
+
+/// Crates on top of the stacks, leaving out empty ones.
+fn get_top_crates(stacks: &[Vec<char>]) -> String {
+    stacks.iter().filter_map(|stack| stack.last()).collect()
+}
+
+impl Solution for Day05 {
+    type Input = Procedure;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_procedure(input)
+    }
+
+    fn part_one(procedure: &Self::Input) -> Self::PartOne {
+        let mut stacks = procedure.stacks.clone();
+        for &(count, from, to) in &procedure.moves {
+            let crates = take_crates(&mut stacks[from], count);
+            stacks[to].extend(crates.into_iter().rev());
+        }
+        get_top_crates(&stacks)
+    }
+
+    fn part_two(procedure: &Self::Input) -> Self::PartTwo {
+        let mut stacks = procedure.stacks.clone();
+        for &(count, from, to) in &procedure.moves {
+            let crates = take_crates(&mut stacks[from], count);
+            stacks[to].extend(crates);
+        }
+        get_top_crates(&stacks)
+    }
+}
+
+util::aoc_tests! {
+    Day05,
+    part1: example1 => "CMZ", input => "GFTNRBZPF";
+    part2: example2 => "MCD", input => "VRQWPDSGP";
+}
@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+
+use util::parse::{Cursor, ParseError};
+use util::Solution;
+
+pub struct Day06;
+
+const PACKET_MARKER_LEN: usize = 4;
+const MESSAGE_MARKER_LEN: usize = 14;
+
+/// Number of characters up to the end of the first marker, `None` if there is none.
+fn find_marker_end(datastream: &str, marker_len: usize) -> Option<usize> {
+    datastream
+        .as_bytes()
+        .windows(marker_len)
+        .position(|window| window.iter().collect::<HashSet<_>>().len() == marker_len)
+        .map(|start| start + marker_len)
+}
+
+impl Solution for Day06 {
+    type Input = String;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut input = Cursor::new(input);
+        let start = input;
+        let datastream = input.line()?;
+        input.end()?;
+        if !datastream.is_ascii() {
+            return Err(start.error("expected ASCII characters"));
+        }
+        // A message marker contains a packet marker, so both exist if it does.
+        if find_marker_end(datastream, MESSAGE_MARKER_LEN).is_none() {
+            return Err(start.error(format!(
+                "no marker of {MESSAGE_MARKER_LEN} different characters"
+            )));
+        }
+        Ok(datastream.to_owned())
+    }
+
+    fn part_one(datastream: &Self::Input) -> Self::PartOne {
+        find_marker_end(datastream, PACKET_MARKER_LEN).expect("parse checked for a marker")
+    }
+
+    fn part_two(datastream: &Self::Input) -> Self::PartTwo {
+        find_marker_end(datastream, MESSAGE_MARKER_LEN).expect("parse checked for a marker")
+    }
+}
+
+util::aoc_tests! {
+    Day06,
+    part1: example1 => "7", input => "1855";
+    part2: example2 => "19", input => "3256";
+}
@@ -1,30 +1,14 @@
-use std::collections::HashSet;
+use aoc2022_day06::Day06;
+use util::Solution;
 
 fn main() {
-    let input = util::get_input_lines().remove(0);
-
-    const PACKET_MARKER_LEN: usize = 4;
-    const MESSAGE_MARKER_LEN: usize = 14;
-
-    for i in 0..(input.len() - PACKET_MARKER_LEN) {
-        let possible_marker = &input[i..(i + PACKET_MARKER_LEN)];
-        if possible_marker.chars().collect::<HashSet<_>>().len() == PACKET_MARKER_LEN {
-            println!(
-                "First packet maker after {} characters",
-                i + PACKET_MARKER_LEN
-            );
-            break;
-        }
-    }
-
-    for i in 0..(input.len() - MESSAGE_MARKER_LEN) {
-        let possible_marker = &input[i..(i + MESSAGE_MARKER_LEN)];
-        if possible_marker.chars().collect::<HashSet<_>>().len() == MESSAGE_MARKER_LEN {
-            println!(
-                "First message maker after {} characters",
-                i + MESSAGE_MARKER_LEN
-            );
-            break;
-        }
-    }
+    let input = util::input::parse_input::<Day06>();
+    println!(
+        "First packet maker after {} characters",
+        Day06::part_one(&input)
+    );
+    println!(
+        "First message maker after {} characters",
+        Day06::part_two(&input)
+    );
 }
@@ -0,0 +1,233 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use util::parse::{Cursor, ParseError};
+use util::Solution;
+
+pub struct Day07;
+
+const TOTAL_DISK_SPACE: u64 = 70000000;
+const NEEDED_SPACE: u64 = 30000000;
+
+#[derive(Debug)]
+pub struct Directory {
+    name: String,
+    entries: Vec<FileOrDirectory>,
+}
+
+impl Directory {
+    fn find_child_dir_mut(&mut self, name: &str) -> Option<&mut Directory> {
+        self.entries
+            .iter_mut()
+            .filter_map(|e| {
+                if let FileOrDirectory::Directory(dir) = e {
+                    Some(dir)
+                } else {
+                    None
+                }
+            })
+            .find(|dir| dir.name == name)
+    }
+
+    /// Directory at a path of names relative to this one.
+    fn get_dir_mut(&mut self, path: &[String]) -> Option<&mut Directory> {
+        path.iter()
+            .try_fold(self, |dir, name| dir.find_child_dir_mut(name))
+    }
+
+    fn get_size(&self) -> u64 {
+        let mut size = 0;
+        for entry in &self.entries {
+            match entry {
+                FileOrDirectory::Directory(dir) => size += dir.get_size(),
+                FileOrDirectory::File(file) => size += u64::from(file.size),
+            }
+        }
+        size
+    }
+
+    fn walk_file_tree<W>(&self, walker: &W)
+    where
+        W: Fn(&FileOrDirectory),
+    {
+        for entry in &self.entries {
+            walker(entry);
+            if let FileOrDirectory::Directory(dir) = entry {
+                dir.walk_file_tree(walker);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct File {
+    size: u32,
+}
+
+#[derive(Debug)]
+enum FileOrDirectory {
+    Directory(Directory),
+    File(File),
+}
+
+#[derive(Debug)]
+enum Command {
+    ChangeDirectory { target: ChangeDirectoryTarget },
+    ListFiles { output: Vec<ListOutput> },
+}
+
+#[derive(Debug)]
+enum ChangeDirectoryTarget {
+    Root,
+    Parent,
+    Directory(String),
+}
+
+#[derive(Debug)]
+enum ListOutput {
+    Directory(String),
+    File(u32),
+}
+
+/// Commands with the position they start at.
+fn parse_history<'a>(input: &mut Cursor<'a>) -> Result<Vec<(Cursor<'a>, Command)>, ParseError> {
+    let mut commands = vec![];
+    while !input.rest().trim().is_empty() {
+        let start = *input;
+        commands.push((start, parse_command(input)?));
+    }
+    Ok(commands)
+}
+
+fn parse_command(input: &mut Cursor) -> Result<Command, ParseError> {
+    input.tag("$ ")?;
+    if input.try_tag("cd ") {
+        let target = match input.line()? {
+            "/" => ChangeDirectoryTarget::Root,
+            ".." => ChangeDirectoryTarget::Parent,
+            target => ChangeDirectoryTarget::Directory(target.to_owned()),
+        };
+        return Ok(Command::ChangeDirectory { target });
+    }
+    if input.try_tag("ls") {
+        if !input.is_at_end() {
+            input.newline()?;
+        }
+        let mut output = vec![];
+        while !input.rest().trim().is_empty() && !input.rest().starts_with('$') {
+            output.push(parse_list_output(input)?);
+        }
+        return Ok(Command::ListFiles { output });
+    }
+    Err(input.error("expected command \"cd\" or \"ls\""))
+}
+
+fn parse_list_output(input: &mut Cursor) -> Result<ListOutput, ParseError> {
+    if input.try_tag("dir ") {
+        return Ok(ListOutput::Directory(input.line()?.to_owned()));
+    }
+    let size = input.integer()?;
+    input.tag(" ")?;
+    input.line()?;
+    Ok(ListOutput::File(size))
+}
+
+/// Builds the tree of the listed files, failing on changes into directories that were not listed.
+fn convert_history_to_file_tree(history: &[(Cursor, Command)]) -> Result<Directory, ParseError> {
+    let mut current_path: Vec<String> = vec![];
+    let mut file_tree = Directory {
+        name: "/".to_owned(),
+        entries: vec![],
+    };
+    let mut current_file = &mut file_tree;
+
+    for (start, command) in history {
+        let unknown_directory = || start.error("directory has not been listed");
+        match command {
+            Command::ChangeDirectory { target } => match target {
+                ChangeDirectoryTarget::Root => {
+                    current_path.clear();
+                    current_file = &mut file_tree;
+                }
+                ChangeDirectoryTarget::Parent => {
+                    current_path.pop();
+                    current_file = file_tree
+                        .get_dir_mut(&current_path)
+                        .ok_or_else(unknown_directory)?;
+                }
+                ChangeDirectoryTarget::Directory(name) => {
+                    current_path.push(name.to_owned());
+                    current_file = current_file
+                        .find_child_dir_mut(name)
+                        .ok_or_else(unknown_directory)?;
+                }
+            },
+            Command::ListFiles { output } => {
+                for line in output {
+                    match line {
+                        ListOutput::Directory(name) => {
+                            current_file
+                                .entries
+                                .push(FileOrDirectory::Directory(Directory {
+                                    name: name.to_owned(),
+                                    entries: vec![],
+                                }));
+                        }
+                        ListOutput::File(size) => {
+                            current_file
+                                .entries
+                                .push(FileOrDirectory::File(File { size: *size }));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(file_tree)
+}
+
+fn get_sorted_dir_sizes(file_tree: &Directory) -> Vec<u64> {
+    let dir_sizes = Rc::new(RefCell::new(vec![]));
+    file_tree.walk_file_tree(&|e| {
+        if let FileOrDirectory::Directory(dir) = e {
+            let size = dir.get_size();
+            dir_sizes.borrow_mut().push(size);
+        }
+    });
+    dir_sizes.borrow_mut().sort_unstable();
+    dir_sizes.take()
+}
+
+impl Solution for Day07 {
+    type Input = Directory;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let commands = parse_history(&mut Cursor::new(input))?;
+        convert_history_to_file_tree(&commands)
+    }
+
+    fn part_one(file_tree: &Self::Input) -> Self::PartOne {
+        get_sorted_dir_sizes(file_tree)
+            .into_iter()
+            .take_while(|s| *s <= 100_000)
+            .sum()
+    }
+
+    fn part_two(file_tree: &Self::Input) -> Self::PartTwo {
+        let total_used_space = file_tree.get_size();
+        let space_to_free = (total_used_space + NEEDED_SPACE).saturating_sub(TOTAL_DISK_SPACE);
+        get_sorted_dir_sizes(file_tree)
+            .into_iter()
+            .find(|s| *s >= space_to_free)
+            // Deleting everything frees enough if no single directory does.
+            .unwrap_or(total_used_space)
+    }
+}
+
+util::aoc_tests! {
+    Day07,
+    part1: example1 => "95437", input => "1844187";
+    part2: example2 => "24933642", input => "4978279";
+}
@@ -0,0 +1,97 @@
+use util::grid::Grid;
+use util::parse::{Cursor, ParseError};
+use util::Solution;
+
+pub struct Day08;
+
+fn is_tree_visible(trees: &Grid<u8>, row: usize, col: usize) -> bool {
+    let height = trees[(col, row)];
+    let tree_row = trees.row(row);
+    let mut is_visible = tree_row[..col].iter().all(|tree| *tree < height);
+    is_visible = is_visible || tree_row[(col + 1)..].iter().all(|tree| *tree < height);
+    is_visible = is_visible || trees.column(col).take(row).all(|tree| *tree < height);
+    is_visible = is_visible || trees.column(col).skip(row + 1).all(|tree| *tree < height);
+    is_visible
+}
+
+fn get_scenic_score(trees: &Grid<u8>, row: usize, col: usize) -> u32 {
+    let height = trees[(col, row)];
+    let tree_row = trees.row(row);
+    let mut score = 1u32;
+    score *= tree_row[..col]
+        .iter()
+        .rev()
+        .enumerate()
+        .find(|(_, tree)| **tree >= height)
+        .map(|(i, _)| i as u32 + 1)
+        .unwrap_or(col as u32);
+    score *= tree_row[(col + 1)..]
+        .iter()
+        .enumerate()
+        .find(|(_, tree)| **tree >= height)
+        .map(|(i, _)| i as u32 + 1)
+        .unwrap_or((trees.width() - col) as u32 - 1);
+    score *= trees
+        .column(col)
+        .take(row)
+        .rev()
+        .enumerate()
+        .find(|(_, tree)| **tree >= height)
+        .map(|(i, _)| i as u32 + 1)
+        .unwrap_or(row as u32);
+    score *= trees
+        .column(col)
+        .skip(row + 1)
+        .enumerate()
+        .find(|(_, tree)| **tree >= height)
+        .map(|(i, _)| i as u32 + 1)
+        .unwrap_or((trees.height() - row) as u32 - 1);
+    score
+}
+
+impl Solution for Day08 {
+    type Input = Grid<u8>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut input = Cursor::new(input);
+        let start = input;
+        let grid = input.grid(|tree| tree.to_digit(10).map(|height| height as u8))?;
+        input.end()?;
+        if grid.width() == 0 || grid.height() == 0 {
+            return Err(start.error("expected trees"));
+        }
+        Ok(grid)
+    }
+
+    fn part_one(grid: &Self::Input) -> Self::PartOne {
+        let mut visible = Grid::new(grid.width(), grid.height(), true);
+        for row in 1..(grid.height() - 1) {
+            for col in 1..(grid.width() - 1) {
+                visible[(col, row)] = is_tree_visible(grid, row, col);
+            }
+        }
+        visible.iter().filter(|(_, v)| **v).count() as u32
+    }
+
+    fn part_two(grid: &Self::Input) -> Self::PartTwo {
+        let mut scores = Grid::new(grid.width(), grid.height(), 0u32);
+        for row in 1..(grid.height() - 1) {
+            for col in 1..(grid.width() - 1) {
+                scores[(col, row)] = get_scenic_score(grid, row, col);
+            }
+        }
+        scores
+            .iter()
+            .map(|(_, score)| *score)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+util::aoc_tests! {
+    Day08,
+    part1: example1 => "21", input => "1820";
+    part2: example2 => "8", input => "385112";
+}
@@ -0,0 +1,145 @@
+use std::collections::VecDeque;
+use std::iter::once;
+
+use util::geometry::{Direction4, Point2};
+use util::grid::{Bounds, SparseGrid};
+use util::parse::{self, ParseError};
+use util::simulation::{Driver, Simulation};
+use util::viz::{Color, Palette, RenderState};
+use util::Solution;
+
+pub struct Day09;
+
+type Position = Point2<i64>;
+
+/// Rope on a plane with `y` growing downwards, whose head moves a cell per step.
+#[derive(Debug)]
+pub struct Rope {
+    tail_positions: SparseGrid<()>,
+    head: Position,
+    knots: Vec<Position>,
+    head_steps: VecDeque<Direction4>,
+}
+
+impl Rope {
+    /// Rope with the given number of knots after the head, which is to make the moves.
+    pub fn new(knots: usize, moves: &[(Direction4, u32)]) -> Self {
+        let mut result = Self {
+            tail_positions: SparseGrid::new(),
+            head: Default::default(),
+            knots: vec![Default::default(); knots],
+            head_steps: moves
+                .iter()
+                .flat_map(|&(direction, steps)| (0..steps).map(move |_| direction))
+                .collect(),
+        };
+        result.tail_positions.insert(0, 0, ());
+        result
+    }
+
+    fn adjust_tails(&mut self) {
+        let mut target = self.head;
+        for knot in self.knots.iter_mut() {
+            *knot = Self::adjust_tail(target, *knot);
+            target = *knot;
+        }
+        self.tail_positions.insert(target.x, target.y, ());
+    }
+
+    fn adjust_tail(target: Position, knot: Position) -> Position {
+        if target.chebyshev_distance(&knot) <= 1 {
+            return knot;
+        }
+        knot + (target - knot).signum()
+    }
+}
+
+impl Simulation for Rope {
+    fn step(&mut self) {
+        if let Some(direction) = self.head_steps.pop_front() {
+            self.head += direction.offset();
+            self.adjust_tails();
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.head_steps.is_empty()
+    }
+}
+
+impl RenderState for Rope {
+    fn bounds(&self) -> Bounds {
+        let mut bounds = self.tail_positions.bounds().unwrap_or(Bounds::new(0, 0));
+        for knot in once(&self.head).chain(&self.knots) {
+            bounds.include(knot.x, knot.y);
+        }
+        bounds.expand(1)
+    }
+
+    fn glyph(&self, x: i64, y: i64) -> char {
+        let position = Position::new(x, y);
+        if self.head == position {
+            'H'
+        } else if let Some(index) = self.knots.iter().position(|knot| *knot == position) {
+            char::from_digit(index as u32 + 1, 10).unwrap()
+        } else if x == 0 && y == 0 {
+            's'
+        } else if self.tail_positions.is_occupied(x, y) {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+/// Colours of the rendered rope, with the knots in shades of orange.
+pub fn get_palette() -> Palette {
+    ('1'..='9').fold(
+        Palette::new()
+            .with('H', Color::RED)
+            .with('s', Color::GREEN)
+            .with('#', Color::GRAY),
+        |palette, knot| palette.with(knot, Color::ORANGE),
+    )
+}
+
+/// Applies the moves to a rope with the given number of knots after the head and counts the
+/// positions the last knot visited.
+pub fn count_tail_positions(moves: &[(Direction4, u32)], knots: usize) -> usize {
+    let mut driver = Driver::new(Rope::new(knots, moves));
+    driver.run();
+    driver.state().tail_positions.len()
+}
+
+impl Solution for Day09 {
+    type Input = Vec<(Direction4, u32)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, |line| {
+            let direction = line.choice(&[
+                ("L", Direction4::Left),
+                ("R", Direction4::Right),
+                ("U", Direction4::Up),
+                ("D", Direction4::Down),
+            ])?;
+            line.tag(" ")?;
+            Ok((direction, line.integer()?))
+        })
+    }
+
+    fn part_one(moves: &Self::Input) -> Self::PartOne {
+        count_tail_positions(moves, 1)
+    }
+
+    fn part_two(moves: &Self::Input) -> Self::PartTwo {
+        count_tail_positions(moves, 9)
+    }
+}
+
+util::aoc_tests! {
+    Day09,
+    part1: example1 => "88", input => "6367";
+    part2: example2 => "36", input => "2536";
+}
@@ -0,0 +1,146 @@
+use util::grid::Grid;
+use util::ocr;
+use util::parse::{self, ParseError};
+use util::simulation::{Driver, Simulation};
+use util::Solution;
+
+pub struct Day10;
+
+#[derive(Debug)]
+pub enum Instruction {
+    NoOp,
+    AddX(i32),
+}
+
+impl Instruction {
+    pub fn get_cycles(&self) -> i32 {
+        match self {
+            Instruction::NoOp => 1,
+            Instruction::AddX(_) => 2,
+        }
+    }
+}
+
+/// Cycles during which the signal strength is measured are 20, 60, 100 and so on.
+fn is_special_cycle(cycle: i32) -> bool {
+    cycle >= 20 && (cycle - 20) % 40 == 0
+}
+
+/// CPU running a program a cycle per step, which draws a pixel of the screen during every cycle.
+#[derive(Debug)]
+pub struct Cpu<'a> {
+    instructions: &'a [Instruction],
+    next_instruction: usize,
+    /// Cycles the next instruction has been running for.
+    instruction_cycles: i32,
+    register_x: i32,
+    cycles: i32,
+    signal_strength_sum: i32,
+    screen: Grid<bool>,
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(instructions: &'a [Instruction]) -> Self {
+        Self {
+            instructions,
+            next_instruction: 0,
+            instruction_cycles: 0,
+            register_x: 1,
+            cycles: 0,
+            signal_strength_sum: 0,
+            screen: Grid::new(40, 6, false),
+        }
+    }
+
+    /// Pixels lit so far, drawn as `#` and `.`.
+    pub fn get_screen(&self) -> Grid<char> {
+        self.screen.map(|lit| if *lit { '#' } else { '.' })
+    }
+}
+
+impl Simulation for Cpu<'_> {
+    fn step(&mut self) {
+        let Some(instruction) = self.instructions.get(self.next_instruction) else {
+            return;
+        };
+        let cycle = self.cycles + 1;
+        if is_special_cycle(cycle) {
+            self.signal_strength_sum += cycle * self.register_x;
+        }
+
+        let x = self.cycles % 40;
+        let y = self.cycles / 40;
+        if ((self.register_x - 1)..=(self.register_x + 1)).contains(&x) {
+            if let Some(pixel) = self.screen.get_mut(x as usize, y as usize) {
+                *pixel = true;
+            }
+        }
+
+        self.cycles = cycle;
+        self.instruction_cycles += 1;
+        if self.instruction_cycles == instruction.get_cycles() {
+            if let Instruction::AddX(val) = instruction {
+                self.register_x += *val;
+            }
+            self.next_instruction += 1;
+            self.instruction_cycles = 0;
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.next_instruction == self.instructions.len()
+    }
+}
+
+fn run_program(instructions: &[Instruction]) -> Cpu<'_> {
+    let mut driver = Driver::new(Cpu::new(instructions));
+    driver.run();
+    driver.into_state()
+}
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+    type PartOne = i32;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, |line| {
+            if line.try_tag("noop") {
+                Ok(Instruction::NoOp)
+            } else if line.try_tag("addx ") {
+                Ok(Instruction::AddX(line.integer()?))
+            } else {
+                Err(line.error("expected instruction \"noop\" or \"addx\""))
+            }
+        })
+    }
+
+    fn part_one(instructions: &Self::Input) -> Self::PartOne {
+        run_program(instructions).signal_strength_sum
+    }
+
+    /// Letters on the screen, or the screen itself if it does not show letters like the example.
+    fn part_two(instructions: &Self::Input) -> Self::PartTwo {
+        let screen = get_screen(instructions);
+        let pixels = screen.map(|pixel| *pixel == '#');
+        ocr::recognize(&pixels).unwrap_or_else(|_| screen.to_string())
+    }
+}
+
+/// Pixels lit by running the program, drawn as `#` and `.`.
+pub fn get_screen(instructions: &[Instruction]) -> Grid<char> {
+    run_program(instructions).get_screen()
+}
+
+util::aoc_tests! {
+    Day10,
+    part1: example1 => "13140", input => "17020";
+    part2:
+        example2 => "##..##..##..##..##..##..##..##..##..##..\n\
+        ###...###...###...###...###...###...###.\n\
+        ####....####....####....####....####....\n\
+        #####.....#####.....#####.....#####.....\n\
+        ######......######......######......####\n\
+        #######.......#######.......#######.....",
+        input => "RLEZFLGE";
+}
@@ -0,0 +1,261 @@
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display, Formatter};
+
+use util::parse::{self, Cursor, ParseError};
+use util::simulation::{Driver, Simulation};
+use util::Solution;
+
+pub struct Day11;
+
+#[derive(Debug, Copy, Clone)]
+enum Operation {
+    Sum,
+    Mul,
+}
+
+#[derive(Debug, Clone)]
+enum OperationValue {
+    OldValue,
+    Value(u64),
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: VecDeque<u64>,
+    operation: Operation,
+    operation_value: OperationValue,
+    test_divisor: u64,
+    test_true_target: u32,
+    test_false_target: u32,
+}
+
+/// The monkeys, of which there are at least two, and the least common multiple of their divisors.
+#[derive(Debug)]
+pub struct Notes {
+    monkeys: Vec<Monkey>,
+    divisor_lcm: u64,
+}
+
+/// Number of a monkey to throw to, which has to be one of the `count` monkeys.
+fn parse_target(block: &mut Cursor, count: usize) -> Result<u32, ParseError> {
+    let start = *block;
+    let target = block.integer::<u32>()?;
+    if target as usize >= count {
+        return Err(start.error(format!("expected monkey from 0 to {}", count - 1)));
+    }
+    Ok(target)
+}
+
+/// Parses the monkey with the given number, one of `count` monkeys.
+fn parse_monkey(block: &mut Cursor, number: usize, count: usize) -> Result<Monkey, ParseError> {
+    block.tag("Monkey ")?;
+    let number_start = *block;
+    if block.integer::<usize>()? != number {
+        return Err(number_start.error(format!("expected monkey {number}")));
+    }
+    block.tag(":")?;
+    block.newline()?;
+
+    block.tag("  Starting items: ")?;
+    let items = block.separated(", ", |item| item.integer())?.into();
+    block.newline()?;
+
+    block.tag("  Operation: new = old ")?;
+    let operation = block.choice(&[("+ ", Operation::Sum), ("* ", Operation::Mul)])?;
+    let operation_value = if block.try_tag("old") {
+        OperationValue::OldValue
+    } else {
+        OperationValue::Value(block.integer()?)
+    };
+    block.newline()?;
+
+    block.tag("  Test: divisible by ")?;
+    let divisor_start = *block;
+    let test_divisor = block.integer()?;
+    if test_divisor == 0 {
+        return Err(divisor_start.error("divisor has to be positive"));
+    }
+    block.newline()?;
+    block.tag("    If true: throw to monkey ")?;
+    let test_true_target = parse_target(block, count)?;
+    block.newline()?;
+    block.tag("    If false: throw to monkey ")?;
+    let test_false_target = parse_target(block, count)?;
+
+    Ok(Monkey {
+        items,
+        operation,
+        operation_value,
+        test_divisor,
+        test_true_target,
+        test_false_target,
+    })
+}
+
+fn parse_notes(input: &str) -> Result<Notes, ParseError> {
+    let count = parse::blocks(input).count();
+    let mut number = 0;
+    let monkeys = parse::parse_blocks(input, |block| {
+        let monkey = parse_monkey(block, number, count)?;
+        number += 1;
+        Ok(monkey)
+    })?;
+    let end = Cursor::at_end_of(input);
+    if monkeys.len() < 2 {
+        return Err(end.error("expected at least two monkeys"));
+    }
+    let divisor_lcm = monkeys
+        .iter()
+        .map(|monkey| monkey.test_divisor)
+        .try_fold(1, util::math::lcm)
+        .ok_or_else(|| end.error("least common multiple of the divisors is too large"))?;
+    Ok(Notes {
+        monkeys,
+        divisor_lcm,
+    })
+}
+
+/// Plays a round, returning `false` if a reduced worry level does not fit in a `u64`.
+///
+/// The operations are computed on `u128`, in which any operation on two `u64` values fits.
+fn play_monkey_round<F>(monkeys: &mut [Monkey], inspections: &mut [u32], worry_reducer: F) -> bool
+where
+    F: Fn(u128) -> Option<u64>,
+{
+    let mut moved_items = vec![];
+    for (monkey_index, monkey) in monkeys.iter_mut().enumerate() {
+        if !moved_items.is_empty() {
+            while let Some(new_item_index) = moved_items
+                .iter()
+                .position(|&(index, _): &(usize, u64)| monkey_index == index)
+            {
+                monkey.items.push_back(moved_items.remove(new_item_index).1);
+            }
+        }
+
+        while let Some(item) = monkey.items.pop_front() {
+            let value = match monkey.operation_value {
+                OperationValue::OldValue => item,
+                OperationValue::Value(value) => value,
+            };
+            let worry = match monkey.operation {
+                Operation::Sum => u128::from(item) + u128::from(value),
+                Operation::Mul => u128::from(item) * u128::from(value),
+            };
+            let Some(item) = worry_reducer(worry) else {
+                return false;
+            };
+
+            inspections[monkey_index] += 1;
+
+            if item % monkey.test_divisor == 0 {
+                moved_items.push((monkey.test_true_target as usize, item));
+            } else {
+                moved_items.push((monkey.test_false_target as usize, item));
+            }
+        }
+    }
+    if !moved_items.is_empty() {
+        for (monkey_index, item) in moved_items {
+            monkeys[monkey_index].items.push_back(item);
+        }
+    }
+    true
+}
+
+/// Monkeys playing keep away a round per step, counting how many items each inspected.
+///
+/// The game ends early if a worry level overflows.
+struct KeepAway<F> {
+    monkeys: Vec<Monkey>,
+    inspections: Vec<u32>,
+    worry_reducer: F,
+    overflowed: bool,
+}
+
+impl<F: Fn(u128) -> Option<u64>> Simulation for KeepAway<F> {
+    fn step(&mut self) {
+        self.overflowed = !play_monkey_round(
+            &mut self.monkeys,
+            &mut self.inspections,
+            &self.worry_reducer,
+        );
+    }
+
+    fn is_finished(&self) -> bool {
+        self.overflowed
+    }
+}
+
+/// Product of the two largest numbers of inspections, unless a worry level overflowed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MonkeyBusiness {
+    Level(u64),
+    Overflow,
+}
+
+impl Display for MonkeyBusiness {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MonkeyBusiness::Level(level) => write!(f, "{level}"),
+            MonkeyBusiness::Overflow => write!(f, "a worry level does not fit in 64 bits"),
+        }
+    }
+}
+
+/// Level of monkey business after the rounds.
+fn get_monkey_business<F>(monkeys: &[Monkey], rounds: usize, worry_reducer: F) -> MonkeyBusiness
+where
+    F: Fn(u128) -> Option<u64>,
+{
+    let mut driver = Driver::new(KeepAway {
+        monkeys: monkeys.to_vec(),
+        inspections: vec![0; monkeys.len()],
+        worry_reducer,
+        overflowed: false,
+    });
+    driver.run_steps(rounds);
+    let state = driver.into_state();
+    if state.overflowed {
+        return MonkeyBusiness::Overflow;
+    }
+    let mut inspections = state.inspections;
+    inspections.sort_unstable();
+    // Two counts of at most `u32::MAX` multiply to less than `u64::MAX`.
+    let level = inspections
+        .iter()
+        .rev()
+        .take(2)
+        .map(|count| *count as u64)
+        .product();
+    MonkeyBusiness::Level(level)
+}
+
+impl Solution for Day11 {
+    type Input = Notes;
+    type PartOne = MonkeyBusiness;
+    type PartTwo = MonkeyBusiness;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_notes(input)
+    }
+
+    fn part_one(notes: &Self::Input) -> Self::PartOne {
+        get_monkey_business(&notes.monkeys, 20, |worry| u64::try_from(worry / 3).ok())
+    }
+
+    /// Worry levels are kept below the least common multiple of the divisors, which does not
+    /// change the tests, so they cannot overflow.
+    fn part_two(notes: &Self::Input) -> Self::PartTwo {
+        let divisor_lcm = u128::from(notes.divisor_lcm);
+        get_monkey_business(&notes.monkeys, 10_000, |worry| {
+            Some((worry % divisor_lcm) as u64)
+        })
+    }
+}
+
+util::aoc_tests! {
+    Day11,
+    part1: example1 => "10605", input => "64032";
+    part2: example2 => "2713310158", input => "12729522272";
+}
@@ -0,0 +1,142 @@
+use std::fmt::{Display, Formatter};
+
+use util::geometry::Point2;
+use util::grid::Grid;
+use util::parse::{Cursor, ParseError};
+use util::search::{a_star_traced, bfs, SearchLog, EXPLORED, FRONTIER};
+use util::viz::{Color, Palette};
+use util::Solution;
+
+pub struct Day12;
+
+type Point = Point2<usize>;
+
+#[derive(Debug)]
+pub struct HeightMap {
+    grid: Grid<i8>,
+    start: Point,
+    end: Point,
+}
+
+/// Fewest steps to the end, which might not be reachable.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Steps {
+    Reached(u32),
+    Unreachable,
+}
+
+impl Display for Steps {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Steps::Reached(steps) => write!(f, "{steps}"),
+            Steps::Unreachable => write!(f, "the end cannot be reached"),
+        }
+    }
+}
+
+fn get_height(c: char) -> i8 {
+    (match c {
+        'S' => 0,
+        'E' => b'z' - b'a',
+        _ => c as u8 - b'a',
+    }) as i8
+}
+
+impl Solution for Day12 {
+    type Input = HeightMap;
+    type PartOne = Steps;
+    type PartTwo = Steps;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut input = Cursor::new(input);
+        let grid = input.grid(|c| matches!(c, 'a'..='z' | 'S' | 'E').then_some(c))?;
+        input.end()?;
+        let find = |marker| {
+            let mut positions = grid.iter().filter(|(_, c)| **c == marker);
+            match (positions.next(), positions.next()) {
+                (Some((position, _)), None) => Ok(Point::from(position)),
+                (None, _) => Err(input.error(format!("missing {marker:?}"))),
+                (Some(_), Some(_)) => Err(input.error(format!("more than one {marker:?}"))),
+            }
+        };
+        Ok(HeightMap {
+            start: find('S')?,
+            end: find('E')?,
+            grid: grid.map(|c| get_height(*c)),
+        })
+    }
+
+    fn part_one(height_map: &Self::Input) -> Self::PartOne {
+        let HeightMap { grid, start, end } = height_map;
+
+        get_steps_to_end(grid, [*start], *end)
+    }
+
+    fn part_two(height_map: &Self::Input) -> Self::PartTwo {
+        let HeightMap { grid, end, .. } = height_map;
+
+        let starts = grid
+            .iter()
+            .filter(|(_, height)| **height == 0)
+            .map(|(position, _)| Point::from(position));
+        get_steps_to_end(grid, starts, *end)
+    }
+}
+
+fn get_successors(grid: &Grid<i8>) -> impl FnMut(Point) -> Vec<Point> + '_ {
+    move |point: Point| {
+        point
+            .neighbours4()
+            .filter(|neighbour| {
+                grid.get(neighbour.x, neighbour.y)
+                    .is_some_and(|height| height - grid[point] <= 1)
+            })
+            .collect()
+    }
+}
+
+/// Fewest steps from the nearest start to the end.
+fn get_steps_to_end<S>(grid: &Grid<i8>, starts: S, end: Point) -> Steps
+where
+    S: IntoIterator<Item = Point>,
+{
+    bfs(starts, get_successors(grid))
+        .find(|(point, _)| *point == end)
+        .map_or(Steps::Unreachable, |(_, steps)| Steps::Reached(steps))
+}
+
+/// Searches from the start to the end like part one, recording which cells it explored.
+pub fn trace_search(height_map: &HeightMap) -> SearchLog<Point> {
+    let HeightMap { grid, start, end } = height_map;
+    let mut log = SearchLog::new();
+    a_star_traced(
+        [*start],
+        get_successors(grid),
+        |point| point == *end,
+        |_| 0,
+        &mut log,
+    );
+    log
+}
+
+/// The height map with the heights as letters, like in the input.
+pub fn draw_heights(height_map: &HeightMap) -> Grid<char> {
+    height_map.grid.map(|height| (b'a' + *height as u8) as char)
+}
+
+/// Colours of the drawn height map, getting lighter with the height, and of the search overlay.
+pub fn get_palette() -> Palette {
+    ('a'..='z')
+        .zip((40..).step_by(6))
+        .fold(Palette::new(), |palette, (height, shade)| {
+            palette.with(height, Color::new(shade, shade, shade))
+        })
+        .with(EXPLORED, Color::YELLOW)
+        .with(FRONTIER, Color::RED)
+}
+
+util::aoc_tests! {
+    Day12,
+    part1: example1 => "31", input => "425";
+    part2: example2 => "29", input => "418";
+}
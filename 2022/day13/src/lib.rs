@@ -0,0 +1,165 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::iter::once;
+
+use util::parse::{self, Cursor, ParseError};
+use util::Solution;
+
+pub struct Day13;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Packet(Vec<PacketData>);
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{}]",
+            self.0
+                .iter()
+                .map(|item| format!("{item}"))
+                .collect::<Vec<_>>()
+                .join(",")
+        )
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PacketData {
+    Integer(u32),
+    List(Vec<PacketData>),
+}
+
+impl Display for PacketData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PacketData::Integer(x) => write!(f, "{x}"),
+            PacketData::List(list) => {
+                write!(
+                    f,
+                    "[{}]",
+                    list.iter()
+                        .map(|item| format!("{item}"))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                )
+            }
+        }
+    }
+}
+
+impl Solution for Day13 {
+    type Input = Vec<(Packet, Packet)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_blocks(input, parse_packet_pair)
+    }
+
+    fn part_one(packets: &Self::Input) -> Self::PartOne {
+        let mut right_order_sum = 0;
+        for (i, (packet_1, packet_2)) in packets.iter().enumerate() {
+            if is_packet_data_in_order(&packet_1.0, &packet_2.0).unwrap() {
+                right_order_sum += i + 1;
+            }
+        }
+        right_order_sum
+    }
+
+    fn part_two(packets: &Self::Input) -> Self::PartTwo {
+        let divider_packet_1 = Packet(vec![PacketData::List(vec![PacketData::Integer(2)])]);
+        let divider_packet_2 = Packet(vec![PacketData::List(vec![PacketData::Integer(6)])]);
+
+        let mut packets = packets
+            .iter()
+            .cloned()
+            .flat_map(|(p1, p2)| [p1, p2])
+            .chain(once(divider_packet_1.clone()))
+            .chain(once(divider_packet_2.clone()))
+            .collect::<Vec<_>>();
+        packets.sort_unstable_by(|a, b| {
+            if is_packet_data_in_order(&a.0, &b.0).unwrap() {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        });
+
+        let divider_1_index = packets
+            .iter()
+            .position(|packet| *packet == divider_packet_1)
+            .unwrap()
+            + 1;
+        let divider_2_index = packets
+            .iter()
+            .position(|packet| *packet == divider_packet_2)
+            .unwrap()
+            + 1;
+        divider_1_index * divider_2_index
+    }
+}
+
+fn parse_packet_pair(block: &mut Cursor) -> Result<(Packet, Packet), ParseError> {
+    let first = parse_packet(block)?;
+    block.newline()?;
+    Ok((first, parse_packet(block)?))
+}
+
+fn parse_packet(input: &mut Cursor) -> Result<Packet, ParseError> {
+    Ok(Packet(parse_packet_data(input)?))
+}
+
+fn parse_packet_data(input: &mut Cursor) -> Result<Vec<PacketData>, ParseError> {
+    input.tag("[")?;
+    if input.try_tag("]") {
+        return Ok(vec![]);
+    }
+    let data = input.separated(",", |input| {
+        if input.rest().starts_with('[') {
+            Ok(PacketData::List(parse_packet_data(input)?))
+        } else {
+            Ok(PacketData::Integer(input.integer()?))
+        }
+    })?;
+    input.tag("]")?;
+    Ok(data)
+}
+
+fn is_packet_data_in_order(data_1: &[PacketData], data_2: &[PacketData]) -> Option<bool> {
+    if data_1.is_empty() {
+        return if data_2.is_empty() { None } else { Some(true) };
+    }
+    for pair in data_1.iter().zip(data_2.iter()) {
+        let result = match pair {
+            (PacketData::Integer(a), PacketData::Integer(b)) => match a.cmp(b) {
+                Ordering::Less => Some(true),
+                Ordering::Equal => None,
+                Ordering::Greater => Some(false),
+            },
+            (PacketData::List(list_1), PacketData::Integer(b)) => {
+                is_packet_data_in_order(list_1, &[PacketData::Integer(*b)])
+            }
+            (PacketData::Integer(a), PacketData::List(list_2)) => {
+                is_packet_data_in_order(&[PacketData::Integer(*a)], list_2)
+            }
+            (PacketData::List(list_1), PacketData::List(list_2)) => {
+                is_packet_data_in_order(list_1, list_2)
+            }
+        };
+        if result.is_some() {
+            return result;
+        }
+    }
+    match data_1.len().cmp(&data_2.len()) {
+        Ordering::Less => Some(true),
+        Ordering::Equal => None,
+        Ordering::Greater => Some(false),
+    }
+}
+
+util::aoc_tests! {
+    Day13,
+    part1: example1 => "13", input => "5196";
+    part2: example2 => "140", input => "22134";
+}
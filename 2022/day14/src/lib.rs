@@ -0,0 +1,188 @@
+use std::cmp::{max, min};
+
+use util::geometry::{Direction8, Point2};
+use util::grid::{Bounds, SparseGrid};
+use util::parse::{self, ParseError};
+use util::simulation::{Driver, Simulation};
+use util::viz::{Color, Palette, RenderState};
+use util::Solution;
+
+pub struct Day14;
+
+pub type Point = Point2<i64>;
+
+const SAND_SOURCE: Point = Point::new(500, 0);
+
+/// Where sand tries to fall to, in order.
+const FALL_DIRECTIONS: [Direction8; 3] = [
+    Direction8::South,
+    Direction8::SouthWest,
+    Direction8::SouthEast,
+];
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Material {
+    Rock,
+    Sand,
+}
+
+/// Cave filling with sand a grain per step, until a grain does not come to rest.
+#[derive(Debug)]
+pub struct Cave {
+    blocks: SparseGrid<Material>,
+    lowest_rock: i64,
+    has_floor: bool,
+    resting_grains: u32,
+    is_full: bool,
+}
+
+impl Cave {
+    pub fn new(rock_paths: &[Vec<Point>], has_floor: bool) -> Self {
+        let mut blocks = SparseGrid::new();
+        draw_rock_paths(&mut blocks, rock_paths);
+        let lowest_rock = blocks.bounds().map_or(0, |bounds| bounds.max_y);
+        Self {
+            blocks,
+            lowest_rock,
+            has_floor,
+            resting_grains: 0,
+            is_full: false,
+        }
+    }
+
+    /// Lets a grain of sand fall from the source until it comes to rest, returning where it did.
+    ///
+    /// Without a floor, sand falling below the lowest rock never comes to rest.
+    pub fn drop_sand(&mut self) -> Option<Point> {
+        let has_floor = self.has_floor;
+        let floor = self.lowest_rock + 2;
+        let is_free = |point: &Point| !self.blocks.is_occupied(point.x, point.y) && point.y < floor;
+        let mut sand = SAND_SOURCE;
+        if !is_free(&sand) {
+            return None;
+        }
+        loop {
+            if !has_floor && sand.y > self.lowest_rock {
+                return None;
+            }
+            match FALL_DIRECTIONS
+                .into_iter()
+                .filter_map(|direction| sand.step(direction))
+                .find(is_free)
+            {
+                Some(point) => sand = point,
+                None => break,
+            }
+        }
+        self.blocks.insert(sand.x, sand.y, Material::Sand);
+        Some(sand)
+    }
+}
+
+impl Simulation for Cave {
+    fn step(&mut self) {
+        match self.drop_sand() {
+            Some(_) => self.resting_grains += 1,
+            None => self.is_full = true,
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.is_full
+    }
+}
+
+impl RenderState for Cave {
+    fn bounds(&self) -> Bounds {
+        let mut bounds = self.blocks.bounds().unwrap_or(Bounds::new(0, 0));
+        bounds.include(SAND_SOURCE.x, SAND_SOURCE.y);
+        bounds.expand(1)
+    }
+
+    fn glyph(&self, x: i64, y: i64) -> char {
+        match self.blocks.get(x, y) {
+            Some(Material::Rock) => '#',
+            Some(Material::Sand) => 'o',
+            None if Point::new(x, y) == SAND_SOURCE => '+',
+            None => '.',
+        }
+    }
+}
+
+/// Colours of the rendered cave.
+pub fn get_palette() -> Palette {
+    Palette::new()
+        .with('#', Color::GRAY)
+        .with('o', Color::YELLOW)
+        .with('+', Color::RED)
+}
+
+/// Drops sand until a grain does not come to rest and returns the number of grains that did.
+pub fn count_resting_sand(rock_paths: &[Vec<Point>], has_floor: bool) -> u32 {
+    let mut driver = Driver::new(Cave::new(rock_paths, has_floor));
+    driver.run();
+    driver.state().resting_grains
+}
+
+impl Solution for Day14 {
+    type Input = Vec<Vec<Point>>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, |line| {
+            let mut previous: Option<Point> = None;
+            line.separated(" -> ", |coordinates| {
+                let start = *coordinates;
+                let x = coordinates.integer()?;
+                coordinates.tag(",")?;
+                let point = Point::new(x, coordinates.integer()?);
+                // Segments have to be horizontal or vertical.
+                if let Some(previous) = previous {
+                    if previous.x != point.x && previous.y != point.y {
+                        return Err(start.error("expected a point in line with the previous one"));
+                    }
+                }
+                previous = Some(point);
+                Ok(point)
+            })
+        })
+    }
+
+    fn part_one(rock_paths: &Self::Input) -> Self::PartOne {
+        count_resting_sand(rock_paths, false)
+    }
+
+    fn part_two(rock_paths: &Self::Input) -> Self::PartTwo {
+        count_resting_sand(rock_paths, true)
+    }
+}
+
+fn draw_rock_paths(blocks: &mut SparseGrid<Material>, rock_paths: &[Vec<Point>]) {
+    for path in rock_paths {
+        if path.is_empty() {
+            continue;
+        }
+        if path.len() == 1 {
+            blocks.insert(path[0].x, path[0].y, Material::Rock);
+            continue;
+        }
+
+        let mut previous = path[0];
+        for &current in path.iter().skip(1) {
+            for x in min(previous.x, current.x)..=max(previous.x, current.x) {
+                blocks.insert(x, current.y, Material::Rock);
+            }
+            for y in min(previous.y, current.y)..=max(previous.y, current.y) {
+                blocks.insert(current.x, y, Material::Rock);
+            }
+            previous = current;
+        }
+    }
+}
+
+util::aoc_tests! {
+    Day14,
+    part1: example1 => "24", input => "1068";
+    part2: example2 => "93", input => "27936";
+}
@@ -0,0 +1,63 @@
+use util::parse::{self, ParseError};
+use util::Solution;
+
+pub struct Day01;
+
+/// The first and last digit of a line as a two-digit number, `None` if it has no digits.
+fn get_calibration_value(line: &str) -> Option<u32> {
+    let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+    let first = digits.next()?;
+    Some(first * 10 + digits.next_back().unwrap_or(first))
+}
+
+/// Adds the digits of spelled out ones, keeping the letters for overlapping names like "twone".
+fn add_spelled_digits(line: &str) -> String {
+    line.replace("one", "one1one")
+        .replace("two", "two2two")
+        .replace("three", "three3three")
+        .replace("four", "four4four")
+        .replace("five", "five5five")
+        .replace("six", "six6six")
+        .replace("seven", "seven7seven")
+        .replace("eight", "eight8eight")
+        .replace("nine", "nine9nine")
+}
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    /// Lines with at least one digit, which may be spelled out.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, |line| {
+            let start = *line;
+            let text = line.line()?;
+            match get_calibration_value(&add_spelled_digits(text)) {
+                Some(_) => Ok(text.to_owned()),
+                None => Err(start.error("expected a digit")),
+            }
+        })
+    }
+
+    /// Lines with only spelled out digits, like in the example of part two, count as 0.
+    fn part_one(lines: &Self::Input) -> Self::PartOne {
+        lines
+            .iter()
+            .filter_map(|line| get_calibration_value(line))
+            .sum()
+    }
+
+    fn part_two(lines: &Self::Input) -> Self::PartTwo {
+        lines
+            .iter()
+            .filter_map(|line| get_calibration_value(&add_spelled_digits(line)))
+            .sum()
+    }
+}
+
+util::aoc_tests! {
+    Day01,
+    part1: example1 => "142", input => "52974";
+    part2: example2 => "281", input => "53340";
+}
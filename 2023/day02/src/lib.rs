@@ -0,0 +1,98 @@
+use std::cmp::max;
+use std::collections::HashMap;
+
+use util::parse::{self, ParseError};
+use util::Solution;
+
+pub struct Day02;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+type ColorSet = HashMap<Color, u32>;
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    draws: Vec<ColorSet>,
+}
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, |line| {
+            line.tag("Game ")?;
+            let id = line.integer()?;
+            line.tag(": ")?;
+            let draws = line.separated("; ", |draw| {
+                let cubes = draw.separated(", ", |cubes| {
+                    let count = cubes.integer()?;
+                    cubes.tag(" ")?;
+                    let color = cubes.choice(&[
+                        ("red", Color::Red),
+                        ("green", Color::Green),
+                        ("blue", Color::Blue),
+                    ])?;
+                    Ok((color, count))
+                })?;
+                Ok(cubes.into_iter().collect::<ColorSet>())
+            })?;
+            Ok(Game { id, draws })
+        })
+    }
+
+    fn part_one(games: &Self::Input) -> Self::PartOne {
+        let mut id_sum = 0;
+        'game: for game in games {
+            for draw in &game.draws {
+                if *draw.get(&Color::Red).unwrap_or(&0) > 12
+                    || *draw.get(&Color::Green).unwrap_or(&0) > 13
+                    || *draw.get(&Color::Blue).unwrap_or(&0) > 14
+                {
+                    continue 'game;
+                }
+            }
+            id_sum += game.id;
+        }
+        id_sum
+    }
+
+    fn part_two(games: &Self::Input) -> Self::PartTwo {
+        let mut power_sum = 0;
+        for game in games {
+            let mut max_colors = ColorSet::new();
+            max_colors.insert(Color::Red, 0);
+            max_colors.insert(Color::Green, 0);
+            max_colors.insert(Color::Blue, 0);
+
+            for draw in &game.draws {
+                if let Some(red) = draw.get(&Color::Red) {
+                    max_colors.insert(Color::Red, max(max_colors[&Color::Red], *red));
+                }
+                if let Some(green) = draw.get(&Color::Green) {
+                    max_colors.insert(Color::Green, max(max_colors[&Color::Green], *green));
+                }
+                if let Some(blue) = draw.get(&Color::Blue) {
+                    max_colors.insert(Color::Blue, max(max_colors[&Color::Blue], *blue));
+                }
+            }
+
+            power_sum +=
+                max_colors[&Color::Red] * max_colors[&Color::Green] * max_colors[&Color::Blue];
+        }
+        power_sum
+    }
+}
+
+util::aoc_tests! {
+    Day02,
+    part1: example1 => "8", input => "2593";
+    part2: example2 => "2286", input => "54699";
+}
@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use util::grid::Grid;
+use util::parse::{Cursor, ParseError};
+use util::Solution;
+
+pub struct Day03;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Cell {
+    Nothing,
+    Number(u32, u32),
+    Symbol(char),
+}
+
+impl Solution for Day03 {
+    type Input = Grid<Cell>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut input = Cursor::new(input);
+        let mut grid = input.grid(|c| {
+            Some(match c {
+                '.' => Cell::Nothing,
+                '0'..='9' => Cell::Number(0, c.to_digit(10).unwrap()),
+                _ => Cell::Symbol(c),
+            })
+        })?;
+        input.end()?;
+
+        let mut num_id = 0;
+        for y in 0..grid.height() {
+            let row = grid.row_mut(y);
+            let mut x = 0;
+            while x < row.len() {
+                if let Cell::Number(_, _) = row[x] {
+                    let start = x;
+                    while let Cell::Number(_, _) = row[x] {
+                        x += 1;
+                        if x >= row.len() {
+                            break;
+                        }
+                    }
+
+                    let digits = &mut row[start..x];
+                    let mut number = 0;
+                    for cell in digits.iter() {
+                        if let Cell::Number(_, n) = cell {
+                            number *= 10;
+                            number += *n;
+                        }
+                    }
+                    digits.fill(Cell::Number(num_id, number));
+                    num_id += 1;
+                }
+                x += 1;
+            }
+        }
+        Ok(grid)
+    }
+
+    fn part_one(grid: &Self::Input) -> Self::PartOne {
+        let mut numbers = HashSet::new();
+        for ((x, y), cell) in grid.iter() {
+            if let Cell::Symbol(_) = cell {
+                for surr_cell in grid.get_surrounding(x, y) {
+                    if let Cell::Number(id, n) = surr_cell {
+                        numbers.insert((*id, *n));
+                    }
+                }
+            }
+        }
+        numbers.iter().map(|(_, n)| n).sum()
+    }
+
+    fn part_two(grid: &Self::Input) -> Self::PartTwo {
+        let mut sum = 0;
+        for ((x, y), cell) in grid.iter() {
+            if let Cell::Symbol('*') = cell {
+                let cells: HashSet<_> = grid
+                    .get_surrounding(x, y)
+                    .iter()
+                    .filter_map(|c| {
+                        if let Cell::Number(id, n) = c {
+                            Some((*id, *n))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+                if cells.len() == 2 {
+                    sum += cells.iter().map(|(_, n)| n).product::<u32>();
+                }
+            }
+        }
+        sum
+    }
+}
+
+util::aoc_tests! {
+    Day03,
+    part1: example1 => "4361", input => "521601";
+    part2: example2 => "467835", input => "80694070";
+}
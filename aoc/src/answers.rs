@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Kind of input an answer belongs to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InputKind {
+    Puzzle,
+    Example,
+}
+
+impl InputKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            InputKind::Puzzle => "input",
+            InputKind::Example => "example",
+        }
+    }
+}
+
+/// Known-correct answers of a day, for the puzzle input and the example of each part.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub example_part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub example_part2: Option<String>,
+}
+
+impl DayAnswers {
+    pub fn get(&self, kind: InputKind, part: u8) -> Option<&str> {
+        self.get_slot(kind, part).as_deref()
+    }
+
+    pub fn set(&mut self, kind: InputKind, part: u8, answer: String) {
+        *self.get_slot_mut(kind, part) = Some(answer);
+    }
+
+    fn get_slot(&self, kind: InputKind, part: u8) -> &Option<String> {
+        match (kind, part) {
+            (InputKind::Puzzle, 1) => &self.part1,
+            (InputKind::Puzzle, 2) => &self.part2,
+            (InputKind::Example, 1) => &self.example_part1,
+            (InputKind::Example, 2) => &self.example_part2,
+            _ => unreachable!(),
+        }
+    }
+
+    fn get_slot_mut(&mut self, kind: InputKind, part: u8) -> &mut Option<String> {
+        match (kind, part) {
+            (InputKind::Puzzle, 1) => &mut self.part1,
+            (InputKind::Puzzle, 2) => &mut self.part2,
+            (InputKind::Example, 1) => &mut self.example_part1,
+            (InputKind::Example, 2) => &mut self.example_part2,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Answers of a year, stored as `<root>/<year>/answers.toml` with one `[dayNN]` table per day.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct YearAnswers {
+    days: BTreeMap<String, DayAnswers>,
+}
+
+impl YearAnswers {
+    pub fn get_path(root: &Path, year: u16) -> PathBuf {
+        root.join(year.to_string()).join("answers.toml")
+    }
+
+    /// Loads the answers of a year, which are empty if nothing has been recorded yet.
+    pub fn load(root: &Path, year: u16) -> Result<Self, String> {
+        let path = Self::get_path(root, year);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)
+            .map_err(|err| format!("could not read '{}': {err}", path.display()))?;
+        let days = toml::from_str(&content)
+            .map_err(|err| format!("invalid answers file '{}': {err}", path.display()))?;
+        Ok(Self { days })
+    }
+
+    pub fn save(&self, root: &Path, year: u16) -> Result<(), String> {
+        let path = Self::get_path(root, year);
+        let content = toml::to_string(&self.days).map_err(|err| err.to_string())?;
+        std::fs::write(&path, content)
+            .map_err(|err| format!("could not write '{}': {err}", path.display()))
+    }
+
+    pub fn get(&self, day: u8) -> Option<&DayAnswers> {
+        self.days.get(&format!("day{day:02}"))
+    }
+
+    pub fn get_mut(&mut self, day: u8) -> &mut DayAnswers {
+        self.days.entry(format!("day{day:02}")).or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InputKind, YearAnswers};
+
+    #[test]
+    fn round_trip() {
+        let mut answers = YearAnswers::default();
+        answers
+            .get_mut(3)
+            .set(InputKind::Example, 2, "70".to_owned());
+        let content = toml::to_string(&answers.days).unwrap();
+        assert_eq!("[day03]\nexample_part2 = \"70\"\n", content);
+
+        let days = toml::from_str(&content).unwrap();
+        assert_eq!(answers, YearAnswers { days });
+    }
+}
@@ -0,0 +1,330 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use util::input::InputSource;
+use util::parse::ParseError;
+
+use crate::registry::{Puzzle, Timings};
+use crate::{input, Options};
+
+const DEFAULT_REPEAT: u32 = 10;
+const DEFAULT_THRESHOLD: u32 = 10;
+/// Slowdowns smaller than this are measurement noise, however large they are relatively.
+const MIN_REGRESSION: u64 = 1_000;
+
+/// Median timings of a day, in nanoseconds.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+struct DayTimings {
+    year: u16,
+    day: u8,
+    parse: u64,
+    part_one: u64,
+    part_two: u64,
+}
+
+impl DayTimings {
+    fn new(puzzle: &Puzzle, timings: &Timings) -> Self {
+        Self {
+            year: puzzle.year,
+            day: puzzle.day,
+            parse: timings.parse.as_nanos() as u64,
+            part_one: timings.part_one.as_nanos() as u64,
+            part_two: timings.part_two.as_nanos() as u64,
+        }
+    }
+
+    /// The phases with their timings, followed by the total.
+    fn get_phases(&self) -> [u64; 4] {
+        let total = self.parse + self.part_one + self.part_two;
+        [self.parse, self.part_one, self.part_two, total]
+    }
+}
+
+/// A saved benchmark run.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+struct Run {
+    commit: String,
+    timestamp: u64,
+    repeat: u32,
+    days: Vec<DayTimings>,
+}
+
+impl Run {
+    fn get_day(&self, year: u16, day: u8) -> Option<&DayTimings> {
+        self.days
+            .iter()
+            .find(|timings| timings.year == year && timings.day == day)
+    }
+}
+
+/// Saved runs, stored as `<root>/bench_history.toml` and not checked in, as timings only compare
+/// on the same machine.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+struct History {
+    #[serde(default)]
+    runs: Vec<Run>,
+}
+
+impl History {
+    fn get_path(root: &Path) -> PathBuf {
+        root.join("bench_history.toml")
+    }
+
+    fn load(root: &Path) -> Result<Self, String> {
+        let path = Self::get_path(root);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)
+            .map_err(|err| format!("could not read '{}': {err}", path.display()))?;
+        toml::from_str(&content)
+            .map_err(|err| format!("invalid history file '{}': {err}", path.display()))
+    }
+
+    fn save(&self, root: &Path) -> Result<(), String> {
+        let path = Self::get_path(root);
+        let content = toml::to_string(self).map_err(|err| err.to_string())?;
+        std::fs::write(&path, content)
+            .map_err(|err| format!("could not write '{}': {err}", path.display()))
+    }
+
+    /// Latest run of a commit given by a prefix of its hash, or the latest run at all.
+    fn get_baseline(&self, commit: Option<&str>) -> Option<&Run> {
+        self.runs
+            .iter()
+            .rev()
+            .find(|run| commit.is_none_or(|commit| run.commit.starts_with(commit)))
+    }
+}
+
+/// Short hash of the checked out commit, marked as dirty if there are uncommitted changes.
+fn get_commit(root: &Path) -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(root)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    };
+    match (
+        git(&["rev-parse", "--short", "HEAD"]),
+        git(&["status", "--porcelain"]),
+    ) {
+        (Some(commit), Some(status)) if !status.is_empty() => format!("{commit}-dirty"),
+        (Some(commit), _) => commit,
+        (None, _) => "unknown".to_owned(),
+    }
+}
+
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+    durations[durations.len() / 2]
+}
+
+/// Runs each phase `repeat` times and returns the median of every phase.
+fn measure(puzzle: &Puzzle, input: &str, repeat: u32) -> Result<Timings, ParseError> {
+    let runs = (0..repeat)
+        .map(|_| (puzzle.bench)(input))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Timings {
+        parse: median(runs.iter().map(|timings| timings.parse).collect()),
+        part_one: median(runs.iter().map(|timings| timings.part_one).collect()),
+        part_two: median(runs.iter().map(|timings| timings.part_two).collect()),
+    })
+}
+
+fn format_duration(nanos: u64) -> String {
+    match nanos {
+        0..1_000 => format!("{nanos} ns"),
+        1_000..1_000_000 => format!("{:.2} µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.2} ms", nanos as f64 / 1e6),
+        _ => format!("{:.2} s", nanos as f64 / 1e9),
+    }
+}
+
+/// Relative change from `old` to `new` in percent.
+fn get_change(old: u64, new: u64) -> f64 {
+    (new as f64 - old as f64) / old.max(1) as f64 * 100.0
+}
+
+fn is_regression(old: u64, new: u64, threshold: u32) -> bool {
+    new.saturating_sub(old) >= MIN_REGRESSION && get_change(old, new) > threshold as f64
+}
+
+const COLUMNS: [&str; 6] = ["YEAR", "DAY", "PARSE", "PART 1", "PART 2", "TOTAL"];
+const WIDTHS: [usize; 6] = [4, 3, 20, 20, 20, 20];
+
+fn print_row(columns: &[String]) {
+    let line = columns
+        .iter()
+        .zip(WIDTHS)
+        .map(|(column, width)| format!("{column:width$}"))
+        .collect::<Vec<_>>()
+        .join("  ");
+    println!("{}", line.trim_end());
+}
+
+/// Times parsing and both parts of every selected day on its puzzle input, comparing the medians
+/// with a saved run.
+pub fn bench(options: &Options) -> ExitCode {
+    let root = options.get_root();
+    let Some(puzzles) = options.get_puzzles() else {
+        return ExitCode::FAILURE;
+    };
+    let repeat = options.repeat.unwrap_or(DEFAULT_REPEAT);
+    let threshold = options.threshold.unwrap_or(DEFAULT_THRESHOLD);
+
+    if cfg!(debug_assertions) {
+        eprintln!(
+            "warning: timing a debug build, use `cargo run --release` for meaningful numbers"
+        );
+    }
+
+    let mut history = match History::load(root) {
+        Ok(history) => history,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let baseline = history.get_baseline(options.baseline.as_deref()).cloned();
+    match (&baseline, &options.baseline) {
+        (Some(baseline), _) => println!(
+            "Comparing with run of {} ({} repeats)\n",
+            baseline.commit, baseline.repeat
+        ),
+        (None, Some(commit)) => {
+            eprintln!("error: no saved run of commit '{commit}'");
+            return ExitCode::FAILURE;
+        }
+        (None, None) => {}
+    }
+
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut days = vec![];
+    let mut regressions = 0;
+    print_row(&COLUMNS.map(str::to_owned));
+    for puzzle in puzzles {
+        let resolver = input::get_resolver(root, puzzle.year, puzzle.day);
+        let input = match resolver.read(&InputSource::Puzzle) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: {} day {:02}: {err}", puzzle.year, puzzle.day);
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let timings = match measure(puzzle, &input, repeat) {
+            Ok(timings) => DayTimings::new(puzzle, &timings),
+            Err(err) => {
+                let err = err.with_file(input::get_file_name(&resolver, &InputSource::Puzzle));
+                eprintln!("error: {} day {:02}: {err}", puzzle.year, puzzle.day);
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let previous = baseline
+            .as_ref()
+            .and_then(|baseline| baseline.get_day(puzzle.year, puzzle.day));
+        let mut columns = vec![puzzle.year.to_string(), format!("{:02}", puzzle.day)];
+        for (phase, &nanos) in timings.get_phases().iter().enumerate() {
+            let column = match previous {
+                Some(previous) => {
+                    let old = previous.get_phases()[phase];
+                    let flag = if is_regression(old, nanos, threshold) {
+                        regressions += 1;
+                        "!"
+                    } else {
+                        ""
+                    };
+                    format!(
+                        "{} ({:+.0}%){flag}",
+                        format_duration(nanos),
+                        get_change(old, nanos)
+                    )
+                }
+                None => format_duration(nanos),
+            };
+            columns.push(column);
+        }
+        print_row(&columns);
+        days.push(timings);
+    }
+
+    if regressions > 0 {
+        println!("\n{regressions} timings got more than {threshold}% slower (marked with !)");
+        exit_code = ExitCode::FAILURE;
+    }
+
+    if options.save {
+        let commit = get_commit(root);
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+        history.runs.push(Run {
+            commit: commit.clone(),
+            timestamp,
+            repeat,
+            days,
+        });
+        match history.save(root) {
+            Ok(()) => println!("\nSaved run of {commit} to the history"),
+            Err(err) => {
+                eprintln!("error: {err}");
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+    exit_code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_duration, is_regression, DayTimings, History, Run};
+
+    #[test]
+    fn durations() {
+        assert_eq!("999 ns", format_duration(999));
+        assert_eq!("1.50 µs", format_duration(1_500));
+        assert_eq!("12.35 ms", format_duration(12_345_678));
+        assert_eq!("2.00 s", format_duration(2_000_000_000));
+    }
+
+    #[test]
+    fn regressions() {
+        assert!(!is_regression(10_000, 11_000, 10));
+        assert!(is_regression(10_000, 11_001, 10));
+        assert!(!is_regression(10_000, 5_000, 10));
+        assert!(!is_regression(50, 500, 10));
+    }
+
+    #[test]
+    fn baseline() {
+        let run = |commit: &str, parse| Run {
+            commit: commit.to_owned(),
+            timestamp: 0,
+            repeat: 1,
+            days: vec![DayTimings {
+                year: 2022,
+                day: 1,
+                parse,
+                part_one: 2,
+                part_two: 3,
+            }],
+        };
+        let history = History {
+            runs: vec![run("abc1234", 1), run("def5678", 2), run("abc1234", 3)],
+        };
+        let content = toml::to_string(&history).unwrap();
+        assert_eq!(history, toml::from_str(&content).unwrap());
+
+        assert_eq!(Some(&history.runs[2]), history.get_baseline(None));
+        assert_eq!(Some(&history.runs[1]), history.get_baseline(Some("def")));
+        assert_eq!(None, history.get_baseline(Some("123")));
+    }
+}
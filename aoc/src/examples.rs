@@ -0,0 +1,263 @@
+use std::path::Path;
+use std::process::ExitCode;
+
+use crate::answers::{InputKind, YearAnswers};
+use crate::new::Layout;
+use crate::{input, Options};
+
+/// Example of a part as found in its `<article class="day-desc">`.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+struct PartExample {
+    /// Content of the first `<pre><code>` block.
+    input: Option<String>,
+    /// The last emphasized code, `<code><em>..</em></code>` or `<em><code>..</code></em>`, which
+    /// is where the descriptions state the example's answer.
+    answer: Option<String>,
+}
+
+/// Contents of the `<tag>` elements with their offsets, assuming they are not nested.
+fn get_elements<'a>(html: &'a str, tag: &str) -> Vec<(usize, &'a str)> {
+    let open = format!("<{tag}");
+    let close = format!("</{tag}>");
+    let mut elements = vec![];
+    let mut offset = 0;
+    while let Some(start) = html[offset..].find(&open).map(|start| offset + start) {
+        let after_name = start + open.len();
+        offset = after_name;
+        if !html[after_name..].starts_with(['>', ' ', '\t', '\n']) {
+            continue;
+        }
+        let Some(content_start) = html[after_name..].find('>').map(|end| after_name + end + 1)
+        else {
+            break;
+        };
+        let Some(content_end) = html[content_start..]
+            .find(&close)
+            .map(|end| content_start + end)
+        else {
+            break;
+        };
+        elements.push((start, &html[content_start..content_end]));
+        offset = content_end + close.len();
+    }
+    elements
+}
+
+/// Text of an HTML fragment, without tags and with character references decoded.
+fn get_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    while let Some(index) = rest.find(['<', '&']) {
+        text.push_str(&rest[..index]);
+        rest = &rest[index..];
+        if rest.starts_with('<') {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            continue;
+        }
+        let decoded = rest.find(';').and_then(|end| {
+            let character = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                entity => {
+                    let code = match entity.strip_prefix("#x").or(entity.strip_prefix("#X")) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => entity.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((character, end + 1))
+        });
+        match decoded {
+            Some((character, length)) => {
+                text.push(character);
+                rest = &rest[length..];
+            }
+            None => {
+                text.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    text.push_str(rest);
+    text
+}
+
+/// Answer of an `<outer><inner>answer</inner></outer>` element, if it is one.
+fn get_emphasized<'a>(element: &'a str, inner: &str) -> Option<&'a str> {
+    element
+        .trim()
+        .strip_prefix(&format!("<{inner}>"))?
+        .strip_suffix(&format!("</{inner}>"))
+}
+
+/// Examples of the parts described in a saved puzzle page, one per description article.
+fn extract_examples(html: &str) -> Vec<PartExample> {
+    get_elements(html, "article")
+        .into_iter()
+        .map(|(_, article)| {
+            let input = get_elements(article, "pre")
+                .first()
+                .map(|(_, block)| get_text(block));
+            let code = get_elements(article, "code")
+                .into_iter()
+                .filter_map(|(offset, code)| Some((offset, get_emphasized(code, "em")?)));
+            let em = get_elements(article, "em")
+                .into_iter()
+                .filter_map(|(offset, em)| Some((offset, get_emphasized(em, "code")?)));
+            let answer = code
+                .chain(em)
+                .max_by_key(|(offset, _)| *offset)
+                .map(|(_, answer)| get_text(answer).trim().to_owned());
+            PartExample { input, answer }
+        })
+        .collect()
+}
+
+/// Whether `content` needs to be written to a file, failing if the file has other content already
+/// and `force` is not set.
+fn needs_write(path: &Path, content: &str, force: bool) -> Result<bool, String> {
+    match std::fs::read_to_string(path) {
+        Ok(existing) if existing == content => Ok(false),
+        Ok(existing) if !existing.trim().is_empty() && !force => Err(format!(
+            "'{}' has a different example already, use --force to replace it",
+            path.display()
+        )),
+        _ => Ok(true),
+    }
+}
+
+fn write_examples(root: &Path, year: u16, day: u8, page: &Path, force: bool) -> Result<(), String> {
+    let html = std::fs::read_to_string(page)
+        .map_err(|err| format!("could not read '{}': {err}", page.display()))?;
+    let day_dir = input::get_day_dir(root, year, day);
+    if !day_dir.is_dir() {
+        return Err(format!(
+            "'{}' does not exist, create the day with 'aoc new' first",
+            day_dir.display()
+        ));
+    }
+
+    let mut parts = extract_examples(&html);
+    let Some(first) = parts.first().cloned() else {
+        return Err(format!("'{}' has no puzzle description", page.display()));
+    };
+    if first.input.is_none() {
+        return Err(format!("'{}' has no example", page.display()));
+    }
+    // The second part usually continues with the example of the first one.
+    parts.truncate(2);
+    if let Some(second) = parts.get_mut(1) {
+        second.input = second.input.take().or(first.input);
+    }
+
+    let layout = Layout::detect(&root.join(year.to_string()))?;
+    let identical = parts
+        .get(1)
+        .is_none_or(|second| second.input == parts[0].input);
+    let mut files = vec![];
+    for (part, file) in parts.iter().zip(layout.get_example_files(identical)) {
+        let path = day_dir.join(file);
+        let content = part.input.clone().unwrap();
+        if !files.iter().any(|(other, _)| *other == path) && needs_write(&path, &content, force)? {
+            files.push((path, content));
+        }
+    }
+
+    let mut answers = YearAnswers::load(root, year)?;
+    let day_answers = answers.get_mut(day);
+    let mut recorded = vec![];
+    for (part, example) in (1..).zip(&parts) {
+        let Some(answer) = &example.answer else {
+            continue;
+        };
+        match day_answers.get(InputKind::Example, part) {
+            Some(existing) if existing == answer => {}
+            Some(existing) if !force => {
+                return Err(format!(
+                    "the example answer of part {part} is '{existing}' already instead of \
+                     '{answer}', use --force to replace it"
+                ));
+            }
+            _ => recorded.push((part, answer.clone())),
+        }
+    }
+
+    for (path, content) in files {
+        std::fs::write(&path, content)
+            .map_err(|err| format!("could not write '{}': {err}", path.display()))?;
+        println!("Wrote {}", path.display());
+    }
+    if !recorded.is_empty() {
+        for (part, answer) in recorded {
+            println!("Example answer of part {part}: {answer}");
+            day_answers.set(InputKind::Example, part, answer);
+        }
+        answers.save(root, year)?;
+        println!("Updated {}", YearAnswers::get_path(root, year).display());
+    }
+    Ok(())
+}
+
+/// Extracts the examples and their answers from a saved puzzle page into the day's example files
+/// and the year's answers.
+pub fn examples(options: &Options) -> ExitCode {
+    let (Some(year), Some(day)) = (options.selection.year, options.selection.day) else {
+        unreachable!("'examples' is only parsed with a year and a day");
+    };
+    let page = options.page.as_deref();
+    let page = page.expect("'examples' is only parsed with a page");
+    match write_examples(options.get_root(), year, day, page, options.force) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract_examples, get_text, PartExample};
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2>
+<p>For example:</p>
+<pre><code>1000
+2000
+
+3000
+</code></pre>
+<p>The Elf carrying the most has <code>3000</code> ... <em>most</em> is <code><em>3000</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>67622</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>The sum is <em><code>6000</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn extract_from_page() {
+        let expected = vec![
+            PartExample {
+                input: Some("1000\n2000\n\n3000\n".to_owned()),
+                answer: Some("3000".to_owned()),
+            },
+            PartExample {
+                input: None,
+                answer: Some("6000".to_owned()),
+            },
+        ];
+        assert_eq!(expected, extract_examples(PAGE));
+    }
+
+    #[test]
+    fn decode_text() {
+        let html = "<em>a</em> &lt;-&gt; b &amp;&amp; &#35;&#x41; &unknown";
+        assert_eq!("a <-> b && #A &unknown", get_text(html));
+    }
+}
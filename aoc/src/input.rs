@@ -0,0 +1,66 @@
+use std::path::{Path, PathBuf};
+
+use util::input::{InputResolver, InputSource};
+
+/// Root of the repository, containing one directory per year.
+pub fn get_root_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+pub fn get_day_dir(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string()).join(format!("day{day:02}"))
+}
+
+pub fn get_resolver(root: &Path, year: u16, day: u8) -> InputResolver {
+    InputResolver::new(get_day_dir(root, year, day))
+}
+
+/// Path of the file an input is read from, for diagnostics.
+pub fn get_file_name(resolver: &InputResolver, source: &InputSource) -> PathBuf {
+    match resolver.resolve(source) {
+        Ok(Some(path)) => path,
+        _ => PathBuf::from("<stdin>"),
+    }
+}
+
+/// Input of a part if none was given explicitly: its example if `DEMO` is set, else the puzzle input.
+pub fn get_default_source(part: u8) -> InputSource {
+    if std::env::var("DEMO").is_ok() {
+        InputSource::Example(part)
+    } else {
+        InputSource::Puzzle
+    }
+}
+
+/// Groups the parts resolving to the same input, so that each input only gets parsed once.
+///
+/// Parts whose input cannot be resolved are grouped by source, so that reading it reports the error
+/// once.
+pub fn group_parts_by_input<F>(
+    resolver: &InputResolver,
+    parts: &[u8],
+    get_source: F,
+) -> Vec<(InputSource, Vec<u8>)>
+where
+    F: Fn(u8) -> InputSource,
+{
+    let mut groups: Vec<(Option<Option<PathBuf>>, InputSource, Vec<u8>)> = vec![];
+    for &part in parts {
+        let source = get_source(part);
+        let resolved = resolver.resolve(&source).ok();
+        match groups.iter_mut().find(|(group_resolved, group_source, _)| {
+            match (group_resolved, &resolved) {
+                (Some(group_resolved), Some(resolved)) => group_resolved == resolved,
+                (None, None) => *group_source == source,
+                _ => false,
+            }
+        }) {
+            Some((_, _, group_parts)) => group_parts.push(part),
+            None => groups.push((resolved, source, vec![part])),
+        }
+    }
+    groups
+        .into_iter()
+        .map(|(_, source, parts)| (source, parts))
+        .collect()
+}
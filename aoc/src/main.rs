@@ -0,0 +1,358 @@
+mod answers;
+mod bench;
+mod examples;
+mod input;
+mod new;
+mod registry;
+mod run;
+mod verify;
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use registry::{Puzzle, PUZZLES};
+use util::input::InputSource;
+
+const USAGE: &str = "\
+Usage: aoc run all [<options>]
+       aoc run <year> [<day>] [<options>]
+       aoc verify [all | <year> [<day>]] [<options>]
+       aoc bench [all | <year> [<day>]] [<options>]
+       aoc new <year> <day> [--root <dir>]
+       aoc examples <year> <day> --from <page.html> [--force] [--root <dir>]
+
+Options:
+  -p, --part <1|2>       Only run one part (run, verify)
+  -i, --input <source>   Read the input from a file, stdin (-), `input` or `exampleN` (run)
+      --record           Store answers that are not known yet as correct (verify)
+  -n, --repeat <n>       Number of timed runs per day [default: 10] (bench)
+      --save             Append the timings to the history file (bench)
+      --baseline <rev>   Compare with the last saved run of a commit [default: last run] (bench)
+      --threshold <pct>  Flag days that got slower by more than this [default: 10] (bench)
+      --from <file>      Saved puzzle page to extract the examples and their answers from (examples)
+      --force            Replace existing examples and example answers (examples)
+      --root <dir>       Directory containing the inputs as <year>/dayNN/ [default: repository]
+
+Set the DEMO environment variable to run against the example inputs.";
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+struct Selection {
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+}
+
+impl Selection {
+    fn matches(&self, puzzle: &Puzzle) -> bool {
+        self.year.is_none_or(|year| year == puzzle.year)
+            && self.day.is_none_or(|day| day == puzzle.day)
+    }
+
+    fn get_parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+struct Options {
+    selection: Selection,
+    input: Option<InputSource>,
+    root: Option<PathBuf>,
+    record: bool,
+    repeat: Option<u32>,
+    save: bool,
+    baseline: Option<String>,
+    threshold: Option<u32>,
+    page: Option<PathBuf>,
+    force: bool,
+}
+
+impl Options {
+    fn get_root(&self) -> &Path {
+        self.root
+            .as_deref()
+            .unwrap_or_else(|| input::get_root_dir())
+    }
+
+    /// Puzzles matching the selection, reporting an error if there are none.
+    fn get_puzzles(&self) -> Option<Vec<&'static Puzzle>> {
+        let puzzles = PUZZLES
+            .iter()
+            .filter(|puzzle| self.selection.matches(puzzle))
+            .collect::<Vec<_>>();
+        if puzzles.is_empty() {
+            eprintln!("error: no puzzle matches the selection");
+            return None;
+        }
+        Some(puzzles)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Command {
+    Run(Options),
+    Verify(Options),
+    Bench(Options),
+    New(Options),
+    Examples(Options),
+}
+
+const RUN_OPTIONS: &[&str] = &["--part", "--input", "--root"];
+const VERIFY_OPTIONS: &[&str] = &["--part", "--record", "--root"];
+const BENCH_OPTIONS: &[&str] = &["--repeat", "--save", "--baseline", "--threshold", "--root"];
+const NEW_OPTIONS: &[&str] = &["--root"];
+const EXAMPLES_OPTIONS: &[&str] = &["--from", "--force", "--root"];
+
+fn parse_args<S: AsRef<str>>(args: &[S]) -> Result<Command, String> {
+    let args = args.iter().map(|arg| arg.as_ref()).collect::<Vec<_>>();
+    match args.first() {
+        Some(&"run") => Ok(Command::Run(parse_options(&args[1..], RUN_OPTIONS, false)?)),
+        Some(&"verify") => Ok(Command::Verify(parse_options(
+            &args[1..],
+            VERIFY_OPTIONS,
+            true,
+        )?)),
+        Some(&"bench") => Ok(Command::Bench(parse_options(
+            &args[1..],
+            BENCH_OPTIONS,
+            true,
+        )?)),
+        Some(&"new") => {
+            let options = parse_options(&args[1..], NEW_OPTIONS, false)?;
+            check_day(&options)?;
+            Ok(Command::New(options))
+        }
+        Some(&"examples") => {
+            let options = parse_options(&args[1..], EXAMPLES_OPTIONS, false)?;
+            check_day(&options)?;
+            if options.page.is_none() {
+                return Err("missing '--from'".to_owned());
+            }
+            Ok(Command::Examples(options))
+        }
+        Some(command) => Err(format!("unknown command '{command}'")),
+        None => Err("missing command".to_owned()),
+    }
+}
+
+/// Checks that a single day is selected, for the commands working on one day.
+fn check_day(options: &Options) -> Result<(), String> {
+    match options.selection.day {
+        Some(1..=25) => Ok(()),
+        Some(day) => Err(format!("invalid day '{day}'")),
+        None => Err("missing day".to_owned()),
+    }
+}
+
+/// Parses the options and the `all | <year> [<day>]` selection, which may be omitted to select all
+/// puzzles if `default_all` is set. Only the options named by their long form in `supported` are
+/// accepted.
+fn parse_options(args: &[&str], supported: &[&str], default_all: bool) -> Result<Options, String> {
+    let mut options = Options::default();
+    let selection = &mut options.selection;
+    let mut positional = vec![];
+    let mut index = 0;
+    while index < args.len() {
+        let option = match args[index] {
+            "-p" => "--part",
+            "-i" => "--input",
+            "-n" => "--repeat",
+            arg => arg,
+        };
+        if option.starts_with("--") && !supported.contains(&option) {
+            return Err(format!("unsupported option '{}'", args[index]));
+        }
+        match option {
+            "--part" => {
+                index += 1;
+                let part = args.get(index).ok_or("missing value for '--part'")?;
+                selection.part = match *part {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return Err(format!("invalid part '{part}'")),
+                };
+            }
+            "--input" => {
+                index += 1;
+                let input = args.get(index).ok_or("missing value for '--input'")?;
+                options.input = Some(input.parse().unwrap());
+            }
+            "--root" => {
+                index += 1;
+                let root = args.get(index).ok_or("missing value for '--root'")?;
+                options.root = Some(PathBuf::from(root));
+            }
+            "--from" => {
+                index += 1;
+                let page = args.get(index).ok_or("missing value for '--from'")?;
+                options.page = Some(PathBuf::from(page));
+            }
+            "--force" => options.force = true,
+            "--record" => options.record = true,
+            "--repeat" => {
+                index += 1;
+                let repeat = args.get(index).ok_or("missing value for '--repeat'")?;
+                options.repeat = match parse_number(repeat, "repeat count")? {
+                    0 => return Err("the repeat count must be positive".to_owned()),
+                    repeat => Some(repeat),
+                };
+            }
+            "--save" => options.save = true,
+            "--baseline" => {
+                index += 1;
+                let baseline = args.get(index).ok_or("missing value for '--baseline'")?;
+                options.baseline = Some(baseline.to_string());
+            }
+            "--threshold" => {
+                index += 1;
+                let threshold = args.get(index).ok_or("missing value for '--threshold'")?;
+                options.threshold = Some(parse_number(threshold, "threshold")?);
+            }
+            arg => positional.push(arg),
+        }
+        index += 1;
+    }
+
+    match positional[..] {
+        ["all"] => {}
+        [] if default_all => {}
+        [year] => selection.year = Some(parse_number(year, "year")?),
+        [year, day] => {
+            selection.year = Some(parse_number(year, "year")?);
+            selection.day = Some(parse_number(day, "day")?);
+        }
+        [] => return Err("missing year or 'all'".to_owned()),
+        _ => return Err("too many arguments".to_owned()),
+    }
+    Ok(options)
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, name: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid {name} '{value}'"))
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match parse_args(&args) {
+        Ok(Command::Run(options)) => run::run(&options),
+        Ok(Command::Verify(options)) => verify::verify(&options),
+        Ok(Command::Bench(options)) => bench::bench(&options),
+        Ok(Command::New(options)) => new::new(&options),
+        Ok(Command::Examples(options)) => examples::examples(&options),
+        Err(err) => {
+            eprintln!("error: {err}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{parse_args, Command, Options, Selection};
+    use util::input::InputSource;
+
+    #[test]
+    fn run_single_part() {
+        let command = parse_args(&["run", "2022", "12", "--part", "2"]);
+        let expected = Options {
+            selection: Selection {
+                year: Some(2022),
+                day: Some(12),
+                part: Some(2),
+            },
+            ..Default::default()
+        };
+        assert_eq!(Ok(Command::Run(expected)), command);
+    }
+
+    #[test]
+    fn run_all() {
+        let command = parse_args(&["run", "all"]);
+        assert_eq!(Ok(Command::Run(Options::default())), command);
+    }
+
+    #[test]
+    fn run_with_input() {
+        let command = parse_args(&["run", "2023", "2", "-i", "-"]);
+        let expected = Options {
+            selection: Selection {
+                year: Some(2023),
+                day: Some(2),
+                part: None,
+            },
+            input: Some(InputSource::Stdin),
+            ..Default::default()
+        };
+        assert_eq!(Ok(Command::Run(expected)), command);
+    }
+
+    #[test]
+    fn invalid_part() {
+        assert!(parse_args(&["run", "2023", "1", "--part", "3"]).is_err());
+    }
+
+    #[test]
+    fn verify_defaults_to_all() {
+        let command = parse_args(&["verify"]);
+        assert_eq!(Ok(Command::Verify(Options::default())), command);
+    }
+
+    #[test]
+    fn record_only_when_verifying() {
+        assert!(parse_args(&["run", "all", "--record"]).is_err());
+        assert!(parse_args(&["verify", "2022", "--record"]).is_ok());
+        assert!(parse_args(&["verify", "--record"]).is_ok());
+    }
+
+    #[test]
+    fn bench_options() {
+        let command = parse_args(&["bench", "2022", "-n", "3", "--save", "--threshold", "25"]);
+        let expected = Options {
+            selection: Selection {
+                year: Some(2022),
+                day: None,
+                part: None,
+            },
+            repeat: Some(3),
+            save: true,
+            threshold: Some(25),
+            ..Default::default()
+        };
+        assert_eq!(Ok(Command::Bench(expected)), command);
+        assert!(parse_args(&["bench", "--repeat", "0"]).is_err());
+        assert!(parse_args(&["bench", "--part", "1"]).is_err());
+    }
+
+    #[test]
+    fn new_needs_day() {
+        assert!(parse_args(&["new", "2023", "4"]).is_ok());
+        assert!(parse_args(&["new", "2023"]).is_err());
+        assert!(parse_args(&["new", "all"]).is_err());
+        assert!(parse_args(&["new", "2023", "26"]).is_err());
+    }
+
+    #[test]
+    fn examples_from_page() {
+        let command = parse_args(&["examples", "2023", "4", "--from", "day4.html", "--force"]);
+        let expected = Options {
+            selection: Selection {
+                year: Some(2023),
+                day: Some(4),
+                part: None,
+            },
+            page: Some(PathBuf::from("day4.html")),
+            force: true,
+            ..Default::default()
+        };
+        assert_eq!(Ok(Command::Examples(expected)), command);
+        assert!(parse_args(&["examples", "2023", "4"]).is_err());
+        assert!(parse_args(&["examples", "2023", "--from", "day4.html"]).is_err());
+    }
+}
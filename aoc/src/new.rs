@@ -0,0 +1,358 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use crate::{input, Options};
+
+/// Layout of the day crates of a year.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Layout {
+    /// A single `src/main.rs` reading `input.txt` or `demo_input.txt` at runtime (2022).
+    Main,
+    /// `src/bin/part1.rs` and `src/bin/part2.rs` including `src/bin/input.txt`, with the examples
+    /// tested in `src/lib.rs` (2023).
+    Bins,
+}
+
+macro_rules! template {
+    ($layout:literal, $path:literal) => {
+        (
+            $path,
+            include_str!(concat!("../templates/", $layout, "/", $path)),
+        )
+    };
+}
+
+const MAIN_TEMPLATES: &[(&str, &str)] = &[
+    template!("main", "Cargo.toml"),
+    template!("main", "src/lib.rs"),
+    template!("main", "src/main.rs"),
+];
+
+const BINS_TEMPLATES: &[(&str, &str)] = &[
+    template!("bins", "Cargo.toml"),
+    template!("bins", "src/lib.rs"),
+    template!("bins", "src/bin/part1.rs"),
+    template!("bins", "src/bin/part2.rs"),
+];
+
+impl Layout {
+    /// Uses the layout of the existing days, preferring the newer one if there are none.
+    ///
+    /// Fails if the days do not all use the same layout.
+    pub fn detect(year_dir: &Path) -> Result<Self, String> {
+        let mut days = std::fs::read_dir(year_dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter(|entry| entry.file_name().to_string_lossy().starts_with("day"))
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .collect::<Vec<_>>();
+        days.sort();
+        let get_layout = |day: &Path| {
+            if day.join("src").join("main.rs").exists() {
+                Layout::Main
+            } else {
+                Layout::Bins
+            }
+        };
+        let Some((first, others)) = days.split_first() else {
+            return Ok(Layout::Bins);
+        };
+        let layout = get_layout(first);
+        match others.iter().find(|day| get_layout(day) != layout) {
+            Some(other) => Err(format!(
+                "'{}' and '{}' have different layouts",
+                first.display(),
+                other.display()
+            )),
+            None => Ok(layout),
+        }
+    }
+
+    fn get_templates(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Layout::Main => MAIN_TEMPLATES,
+            Layout::Bins => BINS_TEMPLATES,
+        }
+    }
+
+    /// Empty input files to paste the puzzle input and the examples into.
+    fn get_placeholders(&self) -> &'static [&'static str] {
+        match self {
+            Layout::Main => &["input.txt", "demo_input.txt"],
+            Layout::Bins => &[
+                "src/bin/input.txt",
+                "src/bin/example1.txt",
+                "src/bin/example2.txt",
+            ],
+        }
+    }
+
+    /// Files of the examples of both parts, relative to the day. Identical examples share
+    /// `demo_input.txt` in the main layout.
+    pub fn get_example_files(&self, identical: bool) -> [&'static str; 2] {
+        match self {
+            Layout::Main if identical => ["demo_input.txt"; 2],
+            Layout::Main => ["example1.txt", "example2.txt"],
+            Layout::Bins => ["src/bin/example1.txt", "src/bin/example2.txt"],
+        }
+    }
+}
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &format!("{day:02}"))
+}
+
+/// Inserts a line among the lines that have a key, keeping them sorted by it.
+///
+/// Returns `None` if a line with the same key exists already.
+fn insert_sorted<K, F>(content: &str, line: &str, get_key: F) -> Option<String>
+where
+    K: Ord,
+    F: Fn(&str) -> Option<K>,
+{
+    let key = get_key(line).expect("inserted line has no key");
+    let mut lines = content.lines().collect::<Vec<_>>();
+    let keyed = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| get_key(line).map(|key| (index, key)))
+        .collect::<Vec<_>>();
+    if keyed.iter().any(|(_, other)| *other == key) {
+        return None;
+    }
+    let index = match keyed.iter().find(|(_, other)| *other > key) {
+        Some((index, _)) => *index,
+        None => keyed.last().map_or(lines.len(), |(index, _)| index + 1),
+    };
+    lines.insert(index, line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Key of a `aoc2022-day01 = { path = ... }` dependency.
+fn get_dependency_key(line: &str) -> Option<(u16, u8)> {
+    let name = line.split_once(" = ")?.0.strip_prefix("aoc")?;
+    let (year, day) = name.split_once("-day")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Key of a `puzzle!(2022, 1, aoc2022_day01::Day01),` registry entry.
+fn get_puzzle_key(line: &str) -> Option<(u16, u8)> {
+    let arguments = line.trim().strip_prefix("puzzle!(")?;
+    let mut arguments = arguments.split(',').map(str::trim);
+    Some((
+        arguments.next()?.parse().ok()?,
+        arguments.next()?.parse().ok()?,
+    ))
+}
+
+/// Adds the day to the `members` of the year's workspace, unless a `day*` glob covers it already.
+fn register_member(content: &str, day: u8) -> Result<Option<String>, String> {
+    let manifest = content
+        .parse::<toml::Table>()
+        .map_err(|err| err.to_string())?;
+    let members = manifest
+        .get("workspace")
+        .and_then(|workspace| workspace.get("members"))
+        .and_then(|members| members.as_array())
+        .ok_or("the workspace has no members")?;
+    let name = format!("day{day:02}");
+    let covered = members
+        .iter()
+        .filter_map(|member| member.as_str())
+        .any(|member| match member.strip_suffix('*') {
+            Some(prefix) => name.starts_with(prefix),
+            None => member == name,
+        });
+    if covered {
+        return Ok(None);
+    }
+    if !content.contains("members = [") {
+        return Err("could not find \"members = [\" to add the day to".to_owned());
+    }
+    Ok(Some(content.replacen(
+        "members = [",
+        &format!("members = [\"{name}\", "),
+        1,
+    )))
+}
+
+/// Change of a file registering a new day.
+struct Registration {
+    path: PathBuf,
+    original: String,
+    updated: String,
+    message: String,
+}
+
+impl Registration {
+    /// The change made by `update`, `None` if it leaves the file as it is.
+    fn prepare<F>(path: &Path, update: F, message: String) -> Result<Option<Self>, String>
+    where
+        F: FnOnce(&str) -> Result<Option<String>, String>,
+    {
+        let original = std::fs::read_to_string(path)
+            .map_err(|err| format!("could not read '{}': {err}", path.display()))?;
+        let updated = update(&original).map_err(|err| format!("'{}': {err}", path.display()))?;
+        Ok(updated.map(|updated| Self {
+            path: path.to_owned(),
+            original,
+            updated,
+            message,
+        }))
+    }
+}
+
+fn create_day(root: &Path, year: u16, day: u8) -> Result<(), String> {
+    let year_dir = root.join(year.to_string());
+    let workspace = year_dir.join("Cargo.toml");
+    if !workspace.is_file() {
+        return Err(format!("'{}' does not exist", workspace.display()));
+    }
+    let day_dir = input::get_day_dir(root, year, day);
+    if day_dir.exists() {
+        return Err(format!("'{}' exists already", day_dir.display()));
+    }
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    let registry = root.join("aoc").join("src").join("registry.rs");
+    let layout = Layout::detect(&year_dir)?;
+
+    // Every registration is checked before anything is written.
+    let dependency = format!("aoc{year}-day{day:02} = {{ path = \"../{year}/day{day:02}\" }}");
+    let puzzle = format!("    puzzle!({year}, {day}, aoc{year}_day{day:02}::Day{day:02}),");
+    let registrations = [
+        Registration::prepare(
+            &workspace,
+            |content| register_member(content, day),
+            format!("Added day{day:02} to {}", workspace.display()),
+        )?,
+        Registration::prepare(
+            &runner_manifest,
+            |content| Ok(insert_sorted(content, &dependency, get_dependency_key)),
+            format!("Added dependency to {}", runner_manifest.display()),
+        )?,
+        Registration::prepare(
+            &registry,
+            |content| Ok(insert_sorted(content, &puzzle, get_puzzle_key)),
+            format!("Registered puzzle in {}", registry.display()),
+        )?,
+    ];
+
+    let mut registered = vec![];
+    let mut scaffold = || {
+        let write = |path: &Path, content: &str| {
+            std::fs::create_dir_all(path.parent().unwrap())
+                .and_then(|_| std::fs::write(path, content))
+                .map_err(|err| format!("could not write '{}': {err}", path.display()))
+        };
+        for (path, template) in layout.get_templates() {
+            let path = day_dir.join(path);
+            write(&path, &render(template, year, day))?;
+            println!("Created {}", path.display());
+        }
+        for path in layout.get_placeholders() {
+            let path = day_dir.join(path);
+            write(&path, "")?;
+            println!("Created {}", path.display());
+        }
+        for registration in registrations.iter().flatten() {
+            write(&registration.path, &registration.updated)?;
+            registered.push(registration);
+            println!("{}", registration.message);
+        }
+        Ok(())
+    };
+    let result = scaffold();
+    if result.is_err() {
+        // Undo the changes, so that the command can be run again.
+        let _ = std::fs::remove_dir_all(&day_dir);
+        for registration in registered {
+            let _ = std::fs::write(&registration.path, &registration.original);
+        }
+    }
+    result
+}
+
+/// Creates the crate of a new day from the template matching its year's layout, and registers it
+/// with the year's workspace and the runner.
+pub fn new(options: &Options) -> ExitCode {
+    let (Some(year), Some(day)) = (options.selection.year, options.selection.day) else {
+        unreachable!("'new' is only parsed with a year and a day");
+    };
+    match create_day(options.get_root(), year, day) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        get_dependency_key, get_puzzle_key, insert_sorted, register_member, render, Layout,
+    };
+
+    #[test]
+    fn render_template() {
+        let rendered = render("use aoc{{year}}_day{{day}}::Day{{day}};", 2023, 4);
+        assert_eq!("use aoc2023_day04::Day04;", rendered);
+    }
+
+    #[test]
+    fn insert_in_order() {
+        let content = "\
+pub const PUZZLES: &[Puzzle] = &[
+    puzzle!(2022, 1, aoc2022_day01::Day01),
+    puzzle!(2022, 10, aoc2022_day10::Day10),
+    puzzle!(2023, 1, aoc2023_day01::Day01),
+];
+";
+        let line = "    puzzle!(2022, 2, aoc2022_day02::Day02),";
+        let inserted = insert_sorted(content, line, get_puzzle_key).unwrap();
+        assert_eq!(Some(2), inserted.lines().position(|other| other == line));
+
+        let line = "    puzzle!(2023, 2, aoc2023_day02::Day02),";
+        let inserted = insert_sorted(content, line, get_puzzle_key).unwrap();
+        assert_eq!(Some(4), inserted.lines().position(|other| other == line));
+
+        let line = "    puzzle!(2023, 1, aoc2023_day01::Day01),";
+        assert_eq!(None, insert_sorted(content, line, get_puzzle_key));
+
+        let dependency = "aoc2023-day04 = { path = \"../2023/day04\" }";
+        assert_eq!(Some((2023, 4)), get_dependency_key(dependency));
+        assert_eq!(None, get_dependency_key("serde = \"1.0\""));
+    }
+
+    #[test]
+    fn register_workspace_member() {
+        let content = "[workspace]\nmembers = [\"day*\", \"util\"]\n";
+        assert_eq!(Ok(None), register_member(content, 4));
+
+        let content = "[workspace]\nmembers = [\"day01\", \"util\"]\n";
+        let expected = "[workspace]\nmembers = [\"day04\", \"day01\", \"util\"]\n";
+        assert_eq!(Ok(Some(expected.to_owned())), register_member(content, 4));
+
+        let content = "[workspace]\nmembers=[\"day01\"]\n";
+        assert!(register_member(content, 4).is_err());
+    }
+
+    #[test]
+    fn detect_layout() {
+        let year_dir = std::env::temp_dir().join("aoc-new-detect-layout");
+        let _ = std::fs::remove_dir_all(&year_dir);
+        assert_eq!(Ok(Layout::Bins), Layout::detect(&year_dir));
+        for day in ["day01", "day02"] {
+            std::fs::create_dir_all(year_dir.join(day).join("src")).unwrap();
+            std::fs::write(year_dir.join(day).join("src").join("main.rs"), "").unwrap();
+        }
+        assert_eq!(Ok(Layout::Main), Layout::detect(&year_dir));
+        std::fs::create_dir_all(year_dir.join("day03").join("src")).unwrap();
+        assert!(Layout::detect(&year_dir).is_err());
+        std::fs::remove_dir_all(&year_dir).unwrap();
+    }
+}
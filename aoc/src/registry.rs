@@ -0,0 +1,87 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use util::parse::ParseError;
+
+/// Solves the given parts of a puzzle for one input, returning the answers in the same order.
+pub type SolveFn = fn(&str, &[u8]) -> Result<Vec<String>, ParseError>;
+
+/// Parses an input and solves both parts once, timing each phase.
+pub type BenchFn = fn(&str) -> Result<Timings, ParseError>;
+
+/// Time spent in each phase of solving a puzzle.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub solve: SolveFn,
+    pub bench: BenchFn,
+}
+
+fn time<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let start = Instant::now();
+    let result = black_box(f());
+    (result, start.elapsed())
+}
+
+fn solve<S: util::Solution>(input: &str, parts: &[u8]) -> Result<Vec<String>, ParseError> {
+    let input = S::parse(input)?;
+    Ok(parts
+        .iter()
+        .map(|part| match part {
+            1 => S::part_one(&input).to_string(),
+            2 => S::part_two(&input).to_string(),
+            _ => unreachable!(),
+        })
+        .collect())
+}
+
+fn bench<S: util::Solution>(input: &str) -> Result<Timings, ParseError> {
+    let (input, parse) = time(|| S::parse(black_box(input)));
+    let input = input?;
+    let (_, part_one) = time(|| S::part_one(&input));
+    let (_, part_two) = time(|| S::part_two(&input));
+    Ok(Timings {
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+macro_rules! puzzle {
+    ($year:literal, $day:literal, $solution:ty) => {
+        Puzzle {
+            year: $year,
+            day: $day,
+            solve: solve::<$solution>,
+            bench: bench::<$solution>,
+        }
+    };
+}
+
+pub const PUZZLES: &[Puzzle] = &[
+    puzzle!(2022, 1, aoc2022_day01::Day01),
+    puzzle!(2022, 2, aoc2022_day02::Day02),
+    puzzle!(2022, 3, aoc2022_day03::Day03),
+    puzzle!(2022, 4, aoc2022_day04::Day04),
+    puzzle!(2022, 5, aoc2022_day05::Day05),
+    puzzle!(2022, 6, aoc2022_day06::Day06),
+    puzzle!(2022, 7, aoc2022_day07::Day07),
+    puzzle!(2022, 8, aoc2022_day08::Day08),
+    puzzle!(2022, 9, aoc2022_day09::Day09),
+    puzzle!(2022, 10, aoc2022_day10::Day10),
+    puzzle!(2022, 11, aoc2022_day11::Day11),
+    puzzle!(2022, 12, aoc2022_day12::Day12),
+    puzzle!(2022, 13, aoc2022_day13::Day13),
+    puzzle!(2022, 14, aoc2022_day14::Day14),
+    puzzle!(2023, 1, aoc2023_day01::Day01),
+    puzzle!(2023, 2, aoc2023_day02::Day02),
+    puzzle!(2023, 3, aoc2023_day03::Day03),
+];
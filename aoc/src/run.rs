@@ -0,0 +1,49 @@
+use std::process::ExitCode;
+
+use crate::{input, Options};
+
+pub fn run(options: &Options) -> ExitCode {
+    let root = options.get_root();
+    let Some(puzzles) = options.get_puzzles() else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+    for puzzle in puzzles {
+        let resolver = input::get_resolver(root, puzzle.year, puzzle.day);
+        let get_source = |part| match &options.input {
+            Some(source) => source.clone(),
+            None => input::get_default_source(part),
+        };
+        for (source, parts) in
+            input::group_parts_by_input(&resolver, &options.selection.get_parts(), get_source)
+        {
+            let input = match resolver.read(&source) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("error: {} day {:02}: {err}", puzzle.year, puzzle.day);
+                    exit_code = ExitCode::FAILURE;
+                    continue;
+                }
+            };
+            let answers = match (puzzle.solve)(&input, &parts) {
+                Ok(answers) => answers,
+                Err(err) => {
+                    let err = err.with_file(input::get_file_name(&resolver, &source));
+                    eprintln!("error: {} day {:02}: {err}", puzzle.year, puzzle.day);
+                    exit_code = ExitCode::FAILURE;
+                    continue;
+                }
+            };
+            for (part, answer) in parts.into_iter().zip(answers) {
+                let label = format!("{} day {:02} part {part}:", puzzle.year, puzzle.day);
+                if answer.contains('\n') {
+                    println!("{label}\n{answer}");
+                } else {
+                    println!("{label} {answer}");
+                }
+            }
+        }
+    }
+    exit_code
+}
@@ -0,0 +1,247 @@
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::process::ExitCode;
+
+use util::input::InputSource;
+
+use crate::answers::{InputKind, YearAnswers};
+use crate::registry::Puzzle;
+use crate::{input, Options};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Status {
+    /// The answer matches the known answer.
+    Pass,
+    /// The answer differs from the known answer.
+    Mismatch { expected: String },
+    /// There is no known answer to compare with.
+    Unknown,
+    /// There was no known answer and the answer got recorded as the known one.
+    Recorded,
+    /// No answer could be computed.
+    Fail(String),
+}
+
+impl Status {
+    fn name(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Mismatch { .. } => "mismatch",
+            Status::Unknown => "unknown",
+            Status::Recorded => "recorded",
+            Status::Fail(_) => "fail",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Check {
+    year: u16,
+    day: u8,
+    part: u8,
+    kind: InputKind,
+    answer: Option<String>,
+    status: Status,
+}
+
+impl Check {
+    fn get_details(&self) -> String {
+        let answer = self.answer.as_deref().map(summarize).unwrap_or_default();
+        match &self.status {
+            Status::Pass | Status::Recorded => answer,
+            Status::Mismatch { expected } => {
+                format!("{answer} (expected {})", summarize(expected))
+            }
+            Status::Unknown => format!("{answer} (no known answer)"),
+            Status::Fail(err) => err.clone(),
+        }
+    }
+}
+
+/// Shortens multi-line answers (like rendered letters) to their first line.
+fn summarize(answer: &str) -> String {
+    let mut lines = answer.lines();
+    let first = lines.next().unwrap_or_default();
+    match lines.count() {
+        0 => first.to_owned(),
+        more => format!("{first} (+{more} lines)"),
+    }
+}
+
+/// Runs the selected puzzles on their puzzle and example inputs and compares the answers with the
+/// known answers stored in `<year>/answers.toml`.
+pub fn verify(options: &Options) -> ExitCode {
+    let root = options.get_root();
+    let Some(puzzles) = options.get_puzzles() else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut answers = BTreeMap::new();
+    let mut checks = vec![];
+
+    // Panics are reported as failed checks, their default message would only garble the table.
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    for puzzle in puzzles {
+        let year_answers = match answers.entry(puzzle.year) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => match YearAnswers::load(root, puzzle.year) {
+                Ok(year_answers) => entry.insert(year_answers),
+                Err(err) => {
+                    panic::set_hook(default_hook);
+                    eprintln!("error: {err}");
+                    return ExitCode::FAILURE;
+                }
+            },
+        };
+
+        for kind in [InputKind::Puzzle, InputKind::Example] {
+            for (part, result) in solve(root, puzzle, kind, &options.selection.get_parts()) {
+                let expected = year_answers
+                    .get(puzzle.day)
+                    .and_then(|day_answers| day_answers.get(kind, part));
+                let status = match (&result, expected) {
+                    (Err(err), _) => Status::Fail(err.clone()),
+                    (Ok(answer), Some(expected)) if answer == expected => Status::Pass,
+                    (Ok(_), Some(expected)) => Status::Mismatch {
+                        expected: expected.to_owned(),
+                    },
+                    (Ok(answer), None) if options.record => {
+                        year_answers
+                            .get_mut(puzzle.day)
+                            .set(kind, part, answer.clone());
+                        Status::Recorded
+                    }
+                    (Ok(_), None) => Status::Unknown,
+                };
+                checks.push(Check {
+                    year: puzzle.year,
+                    day: puzzle.day,
+                    part,
+                    kind,
+                    answer: result.ok(),
+                    status,
+                });
+            }
+        }
+    }
+    panic::set_hook(default_hook);
+
+    print_table(&checks);
+
+    let mut exit_code = ExitCode::SUCCESS;
+    for (year, year_answers) in &answers {
+        let recorded = checks
+            .iter()
+            .any(|check| check.year == *year && check.status == Status::Recorded);
+        if recorded {
+            if let Err(err) = year_answers.save(root, *year) {
+                eprintln!("error: {err}");
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let count = |name| {
+        checks
+            .iter()
+            .filter(|check| check.status.name() == name)
+            .count()
+    };
+    println!();
+    println!(
+        "{} passed, {} mismatched, {} failed, {} unknown, {} recorded",
+        count("pass"),
+        count("mismatch"),
+        count("fail"),
+        count("unknown"),
+        count("recorded")
+    );
+    if count("mismatch") > 0 || count("fail") > 0 {
+        exit_code = ExitCode::FAILURE;
+    }
+    exit_code
+}
+
+/// Solves the given parts for one kind of input, returning the answer or error of each part.
+fn solve(
+    root: &Path,
+    puzzle: &Puzzle,
+    kind: InputKind,
+    parts: &[u8],
+) -> Vec<(u8, Result<String, String>)> {
+    let resolver = input::get_resolver(root, puzzle.year, puzzle.day);
+    let get_source = |part| match kind {
+        InputKind::Puzzle => InputSource::Puzzle,
+        InputKind::Example => InputSource::Example(part),
+    };
+
+    let mut results = vec![];
+    for (source, parts) in input::group_parts_by_input(&resolver, parts, get_source) {
+        let answers = resolver
+            .read(&source)
+            .map_err(|err| err.to_string())
+            .and_then(|input| {
+                panic::catch_unwind(AssertUnwindSafe(|| (puzzle.solve)(&input, &parts)))
+                    .map_err(|payload| {
+                        let message = payload
+                            .downcast_ref::<&str>()
+                            .map(|message| message.to_string())
+                            .or_else(|| payload.downcast_ref::<String>().cloned())
+                            .unwrap_or_default();
+                        format!("panicked: {message}")
+                    })?
+                    .map_err(|err| {
+                        // Only the position and message fit into the table.
+                        let err = err.with_file(input::get_file_name(&resolver, &source));
+                        err.to_string()
+                            .lines()
+                            .next()
+                            .unwrap_or_default()
+                            .to_owned()
+                    })
+            });
+        match answers {
+            Ok(answers) => results.extend(parts.into_iter().zip(answers.into_iter().map(Ok))),
+            Err(err) => results.extend(parts.into_iter().map(|part| (part, Err(err.clone())))),
+        }
+    }
+    results.sort_by_key(|(part, _)| *part);
+    results
+}
+
+fn print_table(checks: &[Check]) {
+    let header = ["YEAR", "DAY", "PART", "INPUT", "STATUS", "ANSWER"].map(str::to_owned);
+    let rows = checks
+        .iter()
+        .map(|check| {
+            [
+                check.year.to_string(),
+                format!("{:02}", check.day),
+                check.part.to_string(),
+                check.kind.name().to_owned(),
+                check.status.name().to_owned(),
+                check.get_details(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut widths = header.clone().map(|column| column.len());
+    for row in &rows {
+        for (width, column) in widths.iter_mut().zip(row) {
+            *width = (*width).max(column.chars().count());
+        }
+    }
+
+    for row in std::iter::once(&header).chain(&rows) {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(column, width)| format!("{column:width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+}
@@ -0,0 +1,7 @@
+use aoc{{year}}_day{{day}}::Day{{day}};
+use util::Solution;
+
+fn main() {
+    let input = util::input::parse_or_exit::<Day{{day}}>(include_str!("input.txt"), "src/bin/input.txt");
+    println!("{}", Day{{day}}::part_one(&input));
+}
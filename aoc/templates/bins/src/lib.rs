@@ -0,0 +1,30 @@
+use util::parse::ParseError;
+use util::Solution;
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    type Input = Vec<String>;
+    type PartOne = &'static str;
+    type PartTwo = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(|line| line.to_owned()).collect())
+    }
+
+    fn part_one(_input: &Self::Input) -> Self::PartOne {
+        "not solved yet"
+    }
+
+    fn part_two(_input: &Self::Input) -> Self::PartTwo {
+        "not solved yet"
+    }
+}
+
+// Test the answers once they are known:
+//
+// util::aoc_tests! {
+//     Day{{day}},
+//     part1: example1 => "", input => "";
+//     part2: example2 => "", input => "";
+// }
@@ -0,0 +1,8 @@
+use aoc{{year}}_day{{day}}::Day{{day}};
+use util::Solution;
+
+fn main() {
+    let input = util::input::parse_input::<Day{{day}}>();
+    println!("Part one: {}", Day{{day}}::part_one(&input));
+    println!("Part two: {}", Day{{day}}::part_two(&input));
+}
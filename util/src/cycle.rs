@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Shape of a sequence of states that repeats: after `prefix_length` steps, every `length` steps
+/// bring back the same state.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Cycle {
+    pub prefix_length: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The smallest step with the same state as step `n`.
+    pub fn get_equivalent_step(&self, n: usize) -> usize {
+        if n < self.prefix_length {
+            n
+        } else {
+            self.prefix_length + (n - self.prefix_length) % self.length
+        }
+    }
+
+    /// State after `n` steps, simulating at most `prefix_length + length` of them.
+    pub fn jump<S, F>(&self, initial: S, mut step: F, n: usize) -> S
+    where
+        F: FnMut(&S) -> S,
+    {
+        let mut state = initial;
+        for _ in 0..self.get_equivalent_step(n) {
+            state = step(&state);
+        }
+        state
+    }
+}
+
+/// Finds the cycle of the states produced by repeatedly applying `step`, using
+/// [Brent's algorithm](https://en.wikipedia.org/wiki/Cycle_detection#Brent's_algorithm).
+///
+/// Only keeps two states at a time, but simulates the prefix and cycle up to three times. Never
+/// returns if the states do not repeat.
+pub fn find_cycle<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut prefix_length = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix_length += 1;
+    }
+    Cycle {
+        prefix_length,
+        length,
+    }
+}
+
+/// The states of a sequence up to the point where it starts to repeat.
+#[derive(Debug, Clone)]
+pub struct CycleStates<S> {
+    cycle: Cycle,
+    states: Vec<S>,
+}
+
+impl<S> CycleStates<S> {
+    pub fn cycle(&self) -> Cycle {
+        self.cycle
+    }
+
+    /// State after `n` steps, without simulating anything.
+    pub fn get(&self, n: usize) -> &S {
+        &self.states[self.cycle.get_equivalent_step(n)]
+    }
+
+    /// The states of the prefix followed by those of one repetition of the cycle.
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+}
+
+/// Finds the cycle of the states produced by repeatedly applying `step`, detecting repetitions by
+/// a fingerprint of each state.
+///
+/// Simulates every state only once but keeps all of them. States with the same fingerprint must
+/// lead to the same future, so the fingerprint may leave out parts of the state that do not
+/// influence it (like a counter). Never returns if the fingerprints do not repeat.
+pub fn find_cycle_by_key<S, K, F, G>(initial: S, mut step: F, mut get_key: G) -> CycleStates<S>
+where
+    K: Eq + Hash,
+    F: FnMut(&S) -> S,
+    G: FnMut(&S) -> K,
+{
+    let mut first_seen = HashMap::new();
+    let mut states = vec![initial];
+    loop {
+        let index = states.len() - 1;
+        let state = &states[index];
+        if let Some(&prefix_length) = first_seen.get(&get_key(state)) {
+            states.pop();
+            return CycleStates {
+                cycle: Cycle {
+                    prefix_length,
+                    length: index - prefix_length,
+                },
+                states,
+            };
+        }
+        first_seen.insert(get_key(state), index);
+        let next = step(state);
+        states.push(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_cycle, find_cycle_by_key, Cycle};
+
+    /// 0, 1, 2, 3, 4, 5, 3, 4, 5, ...
+    fn step(state: &u32) -> u32 {
+        if *state == 5 {
+            3
+        } else {
+            state + 1
+        }
+    }
+
+    const CYCLE: Cycle = Cycle {
+        prefix_length: 3,
+        length: 3,
+    };
+
+    #[test]
+    fn brent() {
+        assert_eq!(CYCLE, find_cycle(0, step));
+        assert_eq!(4, CYCLE.jump(0, step, 1_000_000_000));
+        assert_eq!(2, CYCLE.jump(0, step, 2));
+    }
+
+    #[test]
+    fn fingerprints() {
+        let states = find_cycle_by_key(
+            (0, 0),
+            |(state, n)| (step(state), n + 1),
+            |(state, _)| *state,
+        );
+        assert_eq!(CYCLE, states.cycle());
+        assert_eq!(6, states.states().len());
+        assert_eq!(4, states.get(1_000_000_000).0);
+    }
+}
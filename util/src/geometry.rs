@@ -0,0 +1,327 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::math::Integer;
+
+/// Position on a plane with `y` growing downwards, like the rows of the puzzle inputs.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Difference between two [`Point2`]s.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Vector2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Integer> Point2<T> {
+    /// [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry)
+    ///
+    /// Distance on a square grid with horizontal and vertical movement.
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// [Chebyshev distance](https://en.wikipedia.org/wiki/Chebyshev_distance)
+    ///
+    /// Distance on a square grid with horizontal, vertical and diagonal movement.
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Moves a single step, `None` if the coordinates would not fit.
+    pub fn step<D: Into<Direction8>>(&self, direction: D) -> Option<Self> {
+        let (dx, dy) = direction.into().delta();
+        Some(Self::new(self.x.offset(dx)?, self.y.offset(dy)?))
+    }
+
+    /// The up to 4 horizontally and vertically adjacent points, clockwise starting above.
+    pub fn neighbours4(&self) -> impl Iterator<Item = Self> + '_ {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(|direction| self.step(direction))
+    }
+
+    /// The up to 8 adjacent points including diagonals, clockwise starting above.
+    pub fn neighbours8(&self) -> impl Iterator<Item = Self> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(|direction| self.step(direction))
+    }
+}
+
+impl<T> Vector2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Integer> Vector2<T> {
+    pub fn manhattan_length(&self) -> T {
+        self.x.abs_diff(T::ZERO) + self.y.abs_diff(T::ZERO)
+    }
+
+    pub fn chebyshev_length(&self) -> T {
+        self.x.abs_diff(T::ZERO).max(self.y.abs_diff(T::ZERO))
+    }
+
+    /// Shortens both components to at most one step, keeping their signs.
+    pub fn signum(&self) -> Self {
+        let signum = |value: T| match value.cmp(&T::ZERO) {
+            std::cmp::Ordering::Less => T::ZERO - T::ONE,
+            std::cmp::Ordering::Equal => T::ZERO,
+            std::cmp::Ordering::Greater => T::ONE,
+        };
+        Self::new(signum(self.x), signum(self.y))
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<(T, T)> for Vector2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add<Vector2<T>> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Vector2<T>) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vector2<T>> for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vector2<T>) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Vector2<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign<Vector2<T>> for Point2<T> {
+    fn add_assign(&mut self, rhs: Vector2<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign<Vector2<T>> for Point2<T> {
+    fn sub_assign(&mut self, rhs: Vector2<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vector2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vector2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vector2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vector2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// Horizontal and vertical directions, with up being towards smaller `y`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// All directions, clockwise starting with up.
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Offset of a single step as `(dx, dy)`.
+    pub fn delta(self) -> (i8, i8) {
+        Direction8::from(self).delta()
+    }
+
+    pub fn offset<T: Integer + Neg<Output = T>>(self) -> Vector2<T> {
+        Direction8::from(self).offset()
+    }
+}
+
+/// Horizontal, vertical and diagonal directions, with north being towards smaller `y`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// All directions, clockwise starting with north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Turns by 45 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns by 45 degrees counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Offset of a single step as `(dx, dy)`.
+    pub fn delta(self) -> (i8, i8) {
+        match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        }
+    }
+
+    pub fn offset<T: Integer + Neg<Output = T>>(self) -> Vector2<T> {
+        let component = |delta: i8| match delta {
+            -1 => -T::ONE,
+            0 => T::ZERO,
+            _ => T::ONE,
+        };
+        let (dx, dy) = self.delta();
+        Vector2::new(component(dx), component(dy))
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        match direction {
+            Direction4::Up => Direction8::North,
+            Direction4::Right => Direction8::East,
+            Direction4::Down => Direction8::South,
+            Direction4::Left => Direction8::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction4, Direction8, Point2, Vector2};
+
+    #[test]
+    fn arithmetic_and_distances() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(Vector2::new(3, -4), b - a);
+        assert_eq!(b, a + (b - a));
+        assert_eq!(Vector2::new(1, -1), (b - a).signum());
+        assert_eq!(7, a.manhattan_distance(&b));
+        assert_eq!(4, a.chebyshev_distance(&b));
+        assert_eq!(Point2::new(1, 1), a + Direction4::Up.offset());
+    }
+
+    #[test]
+    fn neighbours() {
+        let corner = Point2::new(0usize, 0);
+        assert_eq!(
+            vec![Point2::new(1, 0), Point2::new(0, 1)],
+            corner.neighbours4().collect::<Vec<_>>()
+        );
+        assert_eq!(3, corner.neighbours8().count());
+        assert_eq!(8, Point2::new(5i32, 5).neighbours8().count());
+    }
+
+    #[test]
+    fn turning() {
+        assert_eq!(Direction4::Left, Direction4::Up.turn_left());
+        assert_eq!(Direction4::Up, Direction4::Left.turn_right());
+        assert_eq!(Direction4::Down, Direction4::Up.reverse());
+        assert_eq!(Direction8::NorthWest, Direction8::North.turn_left());
+        assert_eq!(Direction8::SouthWest, Direction8::NorthEast.reverse());
+    }
+}
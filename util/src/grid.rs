@@ -0,0 +1,349 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::Point2;
+
+mod sparse;
+
+pub use sparse::{Bounds, SparseGrid};
+
+/// A line of the input that is not as long as the first one.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RaggedLineError {
+    /// Zero-based index of the line.
+    pub line: usize,
+    pub width: usize,
+    pub expected: usize,
+}
+
+impl Display for RaggedLineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {} has {} cells, expected {}",
+            self.line + 1,
+            self.width,
+            self.expected
+        )
+    }
+}
+
+impl Error for RaggedLineError {}
+
+/// Rectangular grid of cells, stored row by row in a single `Vec`.
+///
+/// Cells are addressed as `(x, y)`, with `x` being the column and `y` the row, starting at the top
+/// left corner.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a grid from its cells in row order.
+    ///
+    /// # Panics
+    ///
+    /// If the number of cells is not a multiple of `width`.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        let height = match width {
+            0 => 0,
+            _ => cells.len() / width,
+        };
+        assert_eq!(width * height, cells.len(), "cells do not fill the rows");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line, mapping every character to a cell.
+    pub fn from_str<F>(input: &str, mut f: F) -> Result<Self, RaggedLineError>
+    where
+        F: FnMut(char) -> T,
+    {
+        let mut width = None;
+        let mut cells = vec![];
+        for (line_index, line) in input.lines().enumerate() {
+            let len = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            let line_width = cells.len() - len;
+            match width {
+                None => width = Some(line_width),
+                Some(expected) if expected != line_width => {
+                    return Err(RaggedLineError {
+                        line: line_index,
+                        width: line_width,
+                        expected,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(Self::from_vec(width.unwrap_or_default(), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        (0..self.width as isize).contains(&x) && (0..self.height as isize).contains(&y)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// Like [`Grid::get`], with coordinates left or above of the grid being outside as well.
+    pub fn get_signed(&self, x: isize, y: isize) -> Option<&T> {
+        self.get(x.try_into().ok()?, y.try_into().ok()?)
+    }
+
+    pub fn get_signed_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        self.get_mut(x.try_into().ok()?, y.try_into().ok()?)
+    }
+
+    /// Returns the cell of an infinite grid made of copies of this one.
+    ///
+    /// # Panics
+    ///
+    /// If the grid is empty.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self[(x, y)]
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {x} is outside of the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator>
+    {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All cells with their positions in row order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        self.positions().zip(&mut self.cells)
+    }
+
+    /// All positions in row order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Position of the first cell in row order that satisfies the predicate.
+    pub fn position<P>(&self, predicate: P) -> Option<(usize, usize)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let index = self.cells.iter().position(predicate)?;
+        Some((index % self.width, index / self.width))
+    }
+
+    /// The up to 8 cells around a position, in row order.
+    pub fn get_surrounding(&self, x: usize, y: usize) -> Vec<&T> {
+        let mut cells = Vec::with_capacity(8);
+        for neighbour_y in y.saturating_sub(1)..(y + 2).min(self.height) {
+            for neighbour_x in x.saturating_sub(1)..(x + 2).min(self.width) {
+                if neighbour_x != x || neighbour_y != y {
+                    cells.push(&self[(neighbour_x, neighbour_y)]);
+                }
+            }
+        }
+        cells
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, turning rows into columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = self.columns().flatten().cloned().collect();
+        Self {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = self
+            .columns()
+            .flat_map(|column| column.rev())
+            .cloned()
+            .collect();
+        Self {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = self.columns().rev().flatten().cloned().collect();
+        Self {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside of the grid"))
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2<usize>) -> &Self::Output {
+        &self[(point.x, point.y)]
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point2<usize>) -> &mut Self::Output {
+        &mut self[(point.x, point.y)]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    /// Renders one line per row, without a trailing newline.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Grid, RaggedLineError};
+
+    fn example() -> Grid<char> {
+        Grid::from_str("abc\ndef", |c| c).unwrap()
+    }
+
+    #[test]
+    fn parse_and_access() {
+        let grid = example();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&'f'), grid.get(2, 1));
+        assert_eq!(None, grid.get(3, 0));
+        assert_eq!(None, grid.get_signed(-1, 0));
+        assert_eq!(&'c', grid.get_wrapping(-1, 2));
+        assert_eq!(vec!['b', 'e'], grid.column(1).copied().collect::<Vec<_>>());
+        assert_eq!(Some((1, 1)), grid.position(|c| *c == 'e'));
+        assert_eq!(
+            Err(RaggedLineError {
+                line: 1,
+                width: 2,
+                expected: 3
+            }),
+            Grid::from_str("abc\nde", |c| c)
+        );
+        assert!(Grid::from_str("", |c| c).unwrap().is_empty());
+    }
+
+    #[test]
+    fn transform() {
+        let grid = example();
+        assert_eq!("ad\nbe\ncf", grid.transpose().to_string());
+        assert_eq!("da\neb\nfc", grid.rotate_clockwise().to_string());
+        assert_eq!("cf\nbe\nad", grid.rotate_counter_clockwise().to_string());
+        assert_eq!(grid, grid.rotate_clockwise().rotate_counter_clockwise());
+    }
+
+    #[test]
+    fn surrounding() {
+        let grid = Grid::from_str("123\n456\n789", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!(vec![&2, &4, &5], grid.get_surrounding(0, 0));
+        assert_eq!(8, grid.get_surrounding(1, 1).len());
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// Inclusive rectangle of an unbounded grid.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Bounds {
+    pub min_x: i64,
+    pub max_x: i64,
+    pub min_y: i64,
+    pub max_y: i64,
+}
+
+impl Bounds {
+    /// Bounds of a single cell.
+    pub fn new(x: i64, y: i64) -> Self {
+        Self {
+            min_x: x,
+            max_x: x,
+            min_y: y,
+            max_y: y,
+        }
+    }
+
+    pub fn width(&self) -> u64 {
+        self.min_x.abs_diff(self.max_x) + 1
+    }
+
+    pub fn height(&self) -> u64 {
+        self.min_y.abs_diff(self.max_y) + 1
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+
+    /// Grows the bounds to contain the given cell.
+    pub fn include(&mut self, x: i64, y: i64) {
+        self.min_x = self.min_x.min(x);
+        self.max_x = self.max_x.max(x);
+        self.min_y = self.min_y.min(y);
+        self.max_y = self.max_y.max(y);
+    }
+
+    /// Grows the bounds to contain other bounds.
+    pub fn include_bounds(&mut self, other: &Bounds) {
+        self.include(other.min_x, other.min_y);
+        self.include(other.max_x, other.max_y);
+    }
+
+    /// Adds a margin of `amount` cells on every side.
+    pub fn expand(&self, amount: i64) -> Self {
+        Self {
+            min_x: self.min_x - amount,
+            max_x: self.max_x + amount,
+            min_y: self.min_y - amount,
+            max_y: self.max_y + amount,
+        }
+    }
+
+    /// Whether the cell lies on the border of the bounds.
+    fn is_border(&self, x: i64, y: i64) -> bool {
+        x == self.min_x || x == self.max_x || y == self.min_y || y == self.max_y
+    }
+}
+
+/// Grid over the whole plane that only stores occupied cells, keeping track of the bounds of
+/// them.
+///
+/// Like [`super::Grid`], `y` grows downwards when rendering.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i64, i64), T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of occupied cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Smallest rectangle containing all occupied cells, `None` if there are none.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        self.cells.get(&(x, y))
+    }
+
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        self.cells.get_mut(&(x, y))
+    }
+
+    pub fn is_occupied(&self, x: i64, y: i64) -> bool {
+        self.cells.contains_key(&(x, y))
+    }
+
+    /// Occupies a cell, returning its previous value.
+    pub fn insert(&mut self, x: i64, y: i64, value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.include(x, y),
+            None => self.bounds = Some(Bounds::new(x, y)),
+        }
+        self.cells.insert((x, y), value)
+    }
+
+    /// Frees a cell, returning its value.
+    ///
+    /// Freeing a cell on the border of the bounds recomputes them from all remaining cells.
+    pub fn remove(&mut self, x: i64, y: i64) -> Option<T> {
+        let value = self.cells.remove(&(x, y))?;
+        if self.bounds.is_some_and(|bounds| bounds.is_border(x, y)) {
+            self.bounds = None;
+            for &(x, y) in self.cells.keys() {
+                match &mut self.bounds {
+                    Some(bounds) => bounds.include(x, y),
+                    None => self.bounds = Some(Bounds::new(x, y)),
+                }
+            }
+        }
+        Some(value)
+    }
+
+    /// Occupied cells with their positions, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.cells
+            .iter()
+            .map(|(&position, value)| (position, value))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// Renders the given region row by row, mapping every cell to a character, without a trailing
+    /// newline.
+    pub fn render<F>(&self, bounds: Bounds, mut f: F) -> String
+    where
+        F: FnMut((i64, i64), Option<&T>) -> char,
+    {
+        let mut rendered = String::new();
+        for y in bounds.min_y..=bounds.max_y {
+            if y > bounds.min_y {
+                rendered.push('\n');
+            }
+            for x in bounds.min_x..=bounds.max_x {
+                rendered.push(f((x, y), self.get(x, y)));
+            }
+        }
+        rendered
+    }
+}
+
+impl<T> FromIterator<((i64, i64), T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = ((i64, i64), T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for ((x, y), value) in iter {
+            grid.insert(x, y, value);
+        }
+        grid
+    }
+}
+
+impl<T: Display> Display for SparseGrid<T> {
+    /// Renders the occupied region, with free cells as `.`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Some(bounds) = self.bounds else {
+            return Ok(());
+        };
+        for y in bounds.min_y..=bounds.max_y {
+            if y > bounds.min_y {
+                writeln!(f)?;
+            }
+            for x in bounds.min_x..=bounds.max_x {
+                match self.get(x, y) {
+                    Some(value) => write!(f, "{value}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bounds, SparseGrid};
+
+    #[test]
+    fn bounds_follow_cells() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(None, grid.bounds());
+        grid.insert(-2, 1, '#');
+        grid.insert(3, -1, '#');
+        grid.insert(0, 0, 'o');
+        let bounds = Bounds {
+            min_x: -2,
+            max_x: 3,
+            min_y: -1,
+            max_y: 1,
+        };
+        assert_eq!(Some(bounds), grid.bounds());
+        assert_eq!((6, 3), (bounds.width(), bounds.height()));
+        assert_eq!(".....#\n..o...\n#.....", grid.to_string());
+
+        grid.remove(3, -1);
+        let mut bounds = Bounds::new(-2, 1);
+        bounds.include(0, 0);
+        assert_eq!(Some(bounds), grid.bounds());
+        assert_eq!("..o\n#..", grid.to_string());
+    }
+
+    #[test]
+    fn render_region() {
+        let grid = [((0, 0), ())].into_iter().collect::<SparseGrid<_>>();
+        let rendered = grid.render(Bounds::new(0, 0).expand(1), |_, cell| match cell {
+            Some(()) => '#',
+            None => '.',
+        });
+        assert_eq!("...\n.#.\n...", rendered);
+    }
+}
@@ -0,0 +1,249 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::Solution;
+
+/// Environment variable overriding the directory that inputs are resolved against.
+pub const INPUT_ROOT_VAR: &str = "AOC_INPUT_ROOT";
+
+/// Where to read the puzzle input from.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum InputSource {
+    /// The personal puzzle input `input.txt`.
+    Puzzle,
+    /// The `n`-th example of the puzzle description, `example<n>.txt`, falling back to the single
+    /// `demo_input.txt` shared by both parts.
+    Example(u8),
+    /// An explicit file, not resolved against the input root.
+    File(PathBuf),
+    /// Standard input.
+    Stdin,
+}
+
+impl InputSource {
+    /// Selects the input from the first command line argument, falling back to the first example
+    /// if the `DEMO` environment variable is set and to the puzzle input otherwise.
+    pub fn from_env() -> Self {
+        if let Some(arg) = std::env::args().nth(1) {
+            return arg.parse().unwrap();
+        }
+        if std::env::var("DEMO").is_ok() {
+            InputSource::Example(1)
+        } else {
+            InputSource::Puzzle
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = std::convert::Infallible;
+
+    /// `-` is stdin, `input` the puzzle input, `demo`/`example` the first example and `exampleN`
+    /// the `N`-th one. Everything else is taken as a file path.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => InputSource::Stdin,
+            "input" => InputSource::Puzzle,
+            "demo" | "example" => InputSource::Example(1),
+            _ => match s.strip_prefix("example").and_then(|n| n.parse().ok()) {
+                Some(n) => InputSource::Example(n),
+                None => InputSource::File(PathBuf::from(s)),
+            },
+        })
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Puzzle => write!(f, "input"),
+            InputSource::Example(n) => write!(f, "example{n}"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "-"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// None of the candidate files exist.
+    NotFound { tried: Vec<PathBuf> },
+    /// The input exists but could not be read.
+    Io {
+        source: InputSource,
+        error: std::io::Error,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound { tried } => {
+                write!(f, "no input file found, tried: ")?;
+                let tried = tried
+                    .iter()
+                    .map(|path| format!("'{}'", path.display()))
+                    .collect::<Vec<_>>();
+                write!(f, "{}", tried.join(", "))
+            }
+            InputError::Io { source, error } => write!(f, "could not read '{source}': {error}"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } => None,
+            InputError::Io { error, .. } => Some(error),
+        }
+    }
+}
+
+/// Finds the input files of a day below a root directory.
+///
+/// Both layouts are supported: inputs next to `Cargo.toml` (2022) and inputs next to the
+/// `src/bin/part*.rs` binaries (2023).
+#[derive(Debug, Clone)]
+pub struct InputResolver {
+    root: PathBuf,
+}
+
+impl Default for InputResolver {
+    /// Resolves against `$AOC_INPUT_ROOT`, or the current directory if it is not set.
+    fn default() -> Self {
+        match std::env::var_os(INPUT_ROOT_VAR) {
+            Some(root) => Self::new(root),
+            None => Self::new("."),
+        }
+    }
+}
+
+impl InputResolver {
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Paths that are tried in order for the given source.
+    pub fn candidates(&self, source: &InputSource) -> Vec<PathBuf> {
+        let bin_dir = self.root.join("src").join("bin");
+        match source {
+            InputSource::Puzzle => vec![self.root.join("input.txt"), bin_dir.join("input.txt")],
+            InputSource::Example(n) => {
+                let file_name = format!("example{n}.txt");
+                vec![
+                    self.root.join(&file_name),
+                    bin_dir.join(&file_name),
+                    self.root.join("demo_input.txt"),
+                ]
+            }
+            InputSource::File(path) => vec![path.clone()],
+            InputSource::Stdin => vec![],
+        }
+    }
+
+    /// Returns the first existing candidate of the given source, `None` for stdin.
+    pub fn resolve(&self, source: &InputSource) -> Result<Option<PathBuf>, InputError> {
+        if *source == InputSource::Stdin {
+            return Ok(None);
+        }
+        let tried = self.candidates(source);
+        match tried.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(Some(path.clone())),
+            None => Err(InputError::NotFound { tried }),
+        }
+    }
+
+    pub fn read(&self, source: &InputSource) -> Result<String, InputError> {
+        let io_error = |error| InputError::Io {
+            source: source.clone(),
+            error,
+        };
+        match self.resolve(source)? {
+            Some(path) => std::fs::read_to_string(path).map_err(io_error),
+            None => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(io_error)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// Reads the input selected by [`InputSource::from_env`] with the default [`InputResolver`].
+pub fn get_input_string() -> Result<String, InputError> {
+    InputResolver::default().read(&InputSource::from_env())
+}
+
+pub fn get_input_lines() -> Result<Vec<String>, InputError> {
+    Ok(get_input_string()?
+        .lines()
+        .map(|line| line.to_owned())
+        .collect())
+}
+
+fn exit_with_error(error: impl Display) -> ! {
+    eprintln!("error: {error}");
+    std::process::exit(1)
+}
+
+/// Parses `input` read from `file`, or prints why that failed and exits with a non-zero code.
+pub fn parse_or_exit<S: Solution>(input: &str, file: impl AsRef<Path>) -> S::Input {
+    S::parse(input).unwrap_or_else(|error| exit_with_error(error.with_file(file.as_ref())))
+}
+
+/// Reads the input selected by [`InputSource::from_env`] and parses it with [`parse_or_exit`].
+pub fn parse_input<S: Solution>() -> S::Input {
+    let source = InputSource::from_env();
+    let resolver = InputResolver::default();
+    let file = resolver
+        .resolve(&source)
+        .unwrap_or_else(|error| exit_with_error(error))
+        .unwrap_or_else(|| PathBuf::from("<stdin>"));
+    let input = resolver
+        .read(&source)
+        .unwrap_or_else(|error| exit_with_error(error));
+    parse_or_exit::<S>(&input, file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InputError, InputResolver, InputSource};
+    use std::path::PathBuf;
+
+    #[test]
+    fn parse_source() {
+        assert_eq!(Ok(InputSource::Stdin), "-".parse());
+        assert_eq!(Ok(InputSource::Example(1)), "demo".parse());
+        assert_eq!(Ok(InputSource::Example(2)), "example2".parse());
+        assert_eq!(
+            Ok(InputSource::File(PathBuf::from("example.txt"))),
+            "example.txt".parse()
+        );
+    }
+
+    #[test]
+    fn missing_input_names_tried_paths() {
+        let resolver = InputResolver::new("does-not-exist");
+        let Err(InputError::NotFound { tried }) = resolver.read(&InputSource::Example(1)) else {
+            panic!("expected missing input");
+        };
+        assert_eq!(
+            vec![
+                PathBuf::from("does-not-exist/example1.txt"),
+                PathBuf::from("does-not-exist/src/bin/example1.txt"),
+                PathBuf::from("does-not-exist/demo_input.txt"),
+            ],
+            tried
+        );
+    }
+}
@@ -0,0 +1,302 @@
+use std::cmp::{max, min};
+use std::fmt::{Display, Formatter};
+use std::ops::{Range, RangeInclusive};
+
+use crate::math::Integer;
+
+/// Half-open interval `start..end` of integers, empty if `start >= end`.
+///
+/// Inclusive ranges are converted by [`Interval::inclusive`] or [`From<RangeInclusive>`].
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T> Interval<T> {
+    pub const fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+}
+
+impl<T: Integer> Interval<T> {
+    /// Interval of `start..=end`, `None` if `end` is the largest value of `T`.
+    pub fn inclusive(start: T, end: T) -> Option<Self> {
+        Some(Self::new(start, end.offset(1)?))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Number of values in the interval, `None` if it does not fit in `T`.
+    pub fn len(&self) -> Option<T> {
+        if self.is_empty() {
+            Some(T::ZERO)
+        } else {
+            self.end.checked_sub(self.start)
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether every value of `other` is in this interval, which never holds for an empty `other`,
+    /// such as an inverted range.
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        !other.is_empty() && self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Values in both intervals, which might be empty.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(max(self.start, other.start), min(self.end, other.end))
+    }
+
+    /// Splits into the parts before, inside and after `other`, leaving out empty ones.
+    pub fn split(&self, other: &Self) -> (Option<Self>, Option<Self>, Option<Self>) {
+        let non_empty = |interval: Self| (!interval.is_empty()).then_some(interval);
+        let before = Self::new(self.start, min(self.end, other.start));
+        let after = Self::new(max(self.start, other.end), self.end);
+        (
+            non_empty(before),
+            non_empty(self.intersection(other)),
+            non_empty(after),
+        )
+    }
+
+    /// The interval as `start..=end`, `None` if it is empty.
+    pub fn to_inclusive(&self) -> Option<RangeInclusive<T>> {
+        (!self.is_empty()).then(|| self.start..=self.end - T::ONE)
+    }
+}
+
+impl<T> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+/// Meant for literal ranges, see [`Interval::inclusive`] for others.
+///
+/// # Panics
+///
+/// If the end is the largest value of `T`.
+impl<T: Integer> From<RangeInclusive<T>> for Interval<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        Self::inclusive(start, end).expect("inclusive end does not fit")
+    }
+}
+
+impl<T> From<Interval<T>> for Range<T> {
+    fn from(interval: Interval<T>) -> Self {
+        interval.start..interval.end
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// Set of integers stored as sorted, disjoint intervals.
+///
+/// Overlapping and adjacent intervals are coalesced when they are added, so every set has a single
+/// representation.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RangeSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self { intervals: vec![] }
+    }
+}
+
+impl<T: Integer> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of values in the set, `None` if it does not fit in `T`.
+    pub fn len(&self) -> Option<T> {
+        self.intervals
+            .iter()
+            .try_fold(T::ZERO, |len, interval| len.checked_add(interval.len()?))
+    }
+
+    /// The coalesced intervals in ascending order.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    /// Adds all values of a range, given as [`Interval`], [`Range`] or [`RangeInclusive`].
+    pub fn insert<R: Into<Interval<T>>>(&mut self, range: R) {
+        let mut interval = range.into();
+        if interval.is_empty() {
+            return;
+        }
+        // Intervals that overlap or touch the new one are merged into it.
+        let first = self
+            .intervals
+            .partition_point(|other| other.end < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|other| other.start <= interval.end);
+        if first < last {
+            interval.start = min(interval.start, self.intervals[first].start);
+            interval.end = max(interval.end, self.intervals[last - 1].end);
+        }
+        self.intervals.splice(first..last, [interval]);
+    }
+
+    /// Removes all values of a range.
+    pub fn remove<R: Into<Interval<T>>>(&mut self, range: R) {
+        let interval = range.into();
+        if interval.is_empty() {
+            return;
+        }
+        let first = self
+            .intervals
+            .partition_point(|other| other.end <= interval.start);
+        let last = self
+            .intervals
+            .partition_point(|other| other.start < interval.end);
+        if first == last {
+            return;
+        }
+        let before = Interval::new(self.intervals[first].start, interval.start);
+        let after = Interval::new(interval.end, self.intervals[last - 1].end);
+        let remaining = [before, after].into_iter().filter(|part| !part.is_empty());
+        self.intervals.splice(first..last, remaining);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut left, mut right) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(left), other.intervals.get(right)) {
+            let intersection = a.intersection(b);
+            if !intersection.is_empty() {
+                intervals.push(intersection);
+            }
+            if a.end < b.end {
+                left += 1;
+            } else {
+                right += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for interval in other.iter() {
+            difference.remove(interval);
+        }
+        difference
+    }
+}
+
+impl<T: Integer, R: Into<Interval<T>>> Extend<R> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = R>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Integer, R: Into<Interval<T>>> FromIterator<R> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Interval, RangeSet};
+
+    #[test]
+    fn intervals() {
+        let interval = Interval::inclusive(2, 8).unwrap();
+        assert_eq!(Interval::new(2, 9), interval);
+        assert_eq!(Some(7), interval.len());
+        assert_eq!(None, Interval::inclusive(0, u8::MAX));
+        assert_eq!(None, Interval::new(i8::MIN, i8::MAX).len());
+        assert!(interval.contains_interval(&(3..=8).into()));
+        assert!(!interval.contains_interval(&Interval::inclusive(5, 3).unwrap()));
+        assert!(!interval.overlaps(&(9..12).into()));
+        assert_eq!(
+            (Some(Interval::new(2, 5)), Some(Interval::new(5, 9)), None),
+            interval.split(&(5..20).into())
+        );
+        assert_eq!(Some(2..=8), interval.to_inclusive());
+        assert_eq!(None, Interval::new(3, 3).to_inclusive());
+    }
+
+    #[test]
+    fn coalescing() {
+        let mut set = [0..3, 5..7, 3..4, 10..12]
+            .into_iter()
+            .collect::<RangeSet<i64>>();
+        assert_eq!(
+            &[
+                Interval::new(0, 4),
+                Interval::new(5, 7),
+                Interval::new(10, 12)
+            ],
+            set.intervals()
+        );
+        set.insert(4..=9);
+        assert_eq!(&[Interval::new(0, 12)], set.intervals());
+        set.remove(2..5);
+        assert_eq!(
+            &[Interval::new(0, 2), Interval::new(5, 12)],
+            set.intervals()
+        );
+        assert_eq!(Some(9), set.len());
+        assert!(set.contains(1) && !set.contains(2) && set.contains(11));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = [0..10, 20..30].into_iter().collect::<RangeSet<u32>>();
+        let b = [5..15, 15..25].into_iter().collect::<RangeSet<u32>>();
+        let intervals = |set: RangeSet<u32>| set.iter().map(Into::into).collect::<Vec<_>>();
+        assert_eq!(vec![0..30], intervals(a.union(&b)));
+        assert_eq!(vec![5..10, 20..25], intervals(a.intersection(&b)));
+        assert_eq!(vec![0..5, 25..30], intervals(a.difference(&b)));
+        assert_eq!(vec![10..20], intervals(b.difference(&a)));
+    }
+}
@@ -0,0 +1,17 @@
+//! Helpers shared by the solutions of all years.
+
+pub mod cycle;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod ocr;
+pub mod parse;
+pub mod search;
+pub mod simulation;
+mod solution;
+pub mod testing;
+pub mod viz;
+
+pub use solution::Solution;
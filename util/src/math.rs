@@ -0,0 +1,336 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// Integer type usable with the functions of this module and as coordinate of a
+/// [`Point2`](crate::geometry::Point2) or bound of an [`Interval`](crate::interval::Interval).
+pub trait Integer:
+    Copy
+    + Ord
+    + Hash
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+
+    /// Remainder that is never negative for a positive `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is 0.
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    /// Absolute difference, which overflows like the arithmetic operators if it does not fit in a
+    /// signed type.
+    fn abs_diff(self, other: Self) -> Self;
+
+    /// Adds a small signed offset, `None` if the result does not fit.
+    fn offset(self, delta: i8) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    (@impl $t:ty, |$this:ident, $other:ident| $abs_diff:expr, |$value:ident, $delta:ident| $offset:expr) => {
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_div(self, rhs)
+            }
+
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_rem(self, rhs)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$t>::rem_euclid(self, rhs)
+            }
+
+            fn abs_diff($this, $other: Self) -> Self {
+                $abs_diff
+            }
+
+            fn offset($value, $delta: i8) -> Option<Self> {
+                $offset
+            }
+        }
+    };
+    (signed: $($t:ty),*) => {$(
+        impl_integer!(@impl $t, |self, other| (self - other).abs(), |self, delta| {
+            self.checked_add(delta as Self)
+        });
+    )*};
+    (unsigned: $($t:ty),*) => {$(
+        impl_integer!(@impl $t, |self, other| <$t>::abs_diff(self, other), |self, delta| {
+            self.checked_add_signed(delta as _)
+        });
+    )*};
+}
+
+impl_integer!(signed: i8, i16, i32, i64, i128, isize);
+impl_integer!(unsigned: u8, u16, u32, u64, u128, usize);
+
+/// Absolute value, `None` if it does not fit.
+fn checked_abs<T: Integer>(value: T) -> Option<T> {
+    if value < T::ZERO {
+        T::ZERO.checked_sub(value)
+    } else {
+        Some(value)
+    }
+}
+
+/// [Greatest common divisor](https://en.wikipedia.org/wiki/Greatest_common_divisor)
+///
+/// Never negative, `None` if it does not fit (only for the smallest value of a signed type).
+pub fn gcd<T: Integer>(mut a: T, mut b: T) -> Option<T> {
+    while b != T::ZERO {
+        // Only the smallest value of a signed type divided by -1 fails, leaving no remainder.
+        let tmp = a.checked_rem(b).unwrap_or(T::ZERO);
+        a = b;
+        b = tmp;
+    }
+    checked_abs(a)
+}
+
+/// [Least common multiple](https://en.wikipedia.org/wiki/Least_common_multiple)
+///
+/// Never negative, `None` if it does not fit.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    checked_abs(a.checked_div(gcd(a, b)?)?.checked_mul(b)?)
+}
+
+/// [Extended Euclidean algorithm](https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm)
+///
+/// Returns `(gcd, x, y)` with `a * x + b * y == gcd`, `None` if the gcd or a coefficient does not
+/// fit.
+pub fn extended_gcd<T>(a: T, b: T) -> Option<(T, T, T)>
+where
+    T: Integer + Neg<Output = T>,
+{
+    let next = |old: T, current: T, quotient: T| old.checked_sub(quotient.checked_mul(current)?);
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let quotient = old_r.checked_div(r)?;
+        (old_r, r) = (r, next(old_r, r, quotient)?);
+        (old_x, x) = (x, next(old_x, x, quotient)?);
+        (old_y, y) = (y, next(old_y, y, quotient)?);
+    }
+    if old_r < T::ZERO {
+        Some((
+            checked_abs(old_r)?,
+            T::ZERO.checked_sub(old_x)?,
+            T::ZERO.checked_sub(old_y)?,
+        ))
+    } else {
+        Some((old_r, old_x, old_y))
+    }
+}
+
+/// [Modular multiplicative inverse](https://en.wikipedia.org/wiki/Modular_multiplicative_inverse)
+///
+/// Returns `x` in `0..modulus` with `a * x ≡ 1`, `None` if `a` and `modulus` are not coprime or
+/// the modulus is not positive.
+pub fn mod_inverse<T>(a: T, modulus: T) -> Option<T>
+where
+    T: Integer + Neg<Output = T>,
+{
+    if modulus <= T::ZERO {
+        return None;
+    }
+    let (gcd, x, _) = extended_gcd(a.rem_euclid(modulus), modulus)?;
+    (gcd == T::ONE).then(|| x.rem_euclid(modulus))
+}
+
+/// [Modular exponentiation](https://en.wikipedia.org/wiki/Modular_exponentiation)
+///
+/// Returns `base^exponent` reduced to `0..modulus`, `None` if the modulus is not positive or an
+/// intermediate product does not fit.
+pub fn mod_pow<T: Integer>(base: T, mut exponent: u64, modulus: T) -> Option<T> {
+    if modulus <= T::ZERO {
+        return None;
+    }
+    let mut base = base.rem_euclid(modulus);
+    let mut result = T::ONE.rem_euclid(modulus);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result.checked_mul(base)? % modulus;
+        }
+        base = base.checked_mul(base)? % modulus;
+        exponent >>= 1;
+    }
+    Some(result)
+}
+
+/// Why a system of congruences could not be solved.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CrtError {
+    /// No number satisfies all congruences.
+    Incompatible,
+    /// A modulus is not positive.
+    InvalidModulus,
+    /// The solution or an intermediate result does not fit.
+    Overflow,
+}
+
+impl Display for CrtError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrtError::Incompatible => write!(f, "congruences are incompatible"),
+            CrtError::InvalidModulus => write!(f, "moduli have to be positive"),
+            CrtError::Overflow => write!(f, "arithmetic overflow"),
+        }
+    }
+}
+
+impl Error for CrtError {}
+
+/// [Chinese remainder theorem](https://en.wikipedia.org/wiki/Chinese_remainder_theorem)
+///
+/// Solves `x ≡ residue (mod modulus)` for all `(residue, modulus)` pairs, returning the smallest
+/// non-negative `x` and the least common multiple of the moduli, which all solutions differ by.
+/// Moduli have to be positive but do not have to be coprime.
+pub fn chinese_remainder<T, I>(congruences: I) -> Result<(T, T), CrtError>
+where
+    T: Integer + Neg<Output = T>,
+    I: IntoIterator<Item = (T, T)>,
+{
+    let (mut solution, mut lcm) = (T::ZERO, T::ONE);
+    for (residue, modulus) in congruences {
+        if modulus <= T::ZERO {
+            return Err(CrtError::InvalidModulus);
+        }
+        let residue = residue.rem_euclid(modulus);
+        let gcd = gcd(lcm, modulus).ok_or(CrtError::Overflow)?;
+        let difference = residue - solution.rem_euclid(modulus);
+        if difference % gcd != T::ZERO {
+            return Err(CrtError::Incompatible);
+        }
+        // Find `k` with `solution + lcm * k ≡ residue (mod modulus)`.
+        let reduced_modulus = modulus / gcd;
+        let inverse = mod_inverse(lcm / gcd, reduced_modulus).ok_or(CrtError::Overflow)?;
+        let k = (difference / gcd)
+            .rem_euclid(reduced_modulus)
+            .checked_mul(inverse)
+            .ok_or(CrtError::Overflow)?
+            % reduced_modulus;
+        let next_lcm = (lcm / gcd).checked_mul(modulus).ok_or(CrtError::Overflow)?;
+        solution = lcm
+            .checked_mul(k)
+            .and_then(|offset| solution.checked_add(offset))
+            .ok_or(CrtError::Overflow)?
+            .rem_euclid(next_lcm);
+        lcm = next_lcm;
+    }
+    Ok((solution, lcm))
+}
+
+/// [Integer square root](https://en.wikipedia.org/wiki/Integer_square_root)
+///
+/// Largest number whose square is at most `n`, `None` if `n` is negative.
+pub fn isqrt<T: Integer>(n: T) -> Option<T> {
+    let two = T::ONE + T::ONE;
+    if n < T::ZERO {
+        return None;
+    }
+    if n < two {
+        return Some(n);
+    }
+    // Newton's method, which decreases monotonically from a start above the root.
+    let mut x = n / two + T::ONE;
+    let mut y = (x + n / x) / two;
+    while y < x {
+        x = y;
+        y = (x + n / x) / two;
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{chinese_remainder, extended_gcd, gcd, isqrt, lcm, mod_inverse, mod_pow, CrtError};
+
+    #[test]
+    fn divisors() {
+        assert_eq!(Some(6), gcd(54u32, 24));
+        assert_eq!(Some(6), gcd(-54, 24));
+        assert_eq!(None, gcd(i8::MIN, 0));
+        assert_eq!(Some(1), gcd(i64::MIN, -1));
+        assert_eq!(Some(1), gcd(-1, i64::MIN));
+        assert_eq!(None, lcm(i64::MIN, -1));
+        assert_eq!(Some(i64::MAX), lcm(i64::MAX, -1));
+        assert_eq!(Some(36), lcm(12u64, 18));
+        assert_eq!(Some(0), lcm(0, 5));
+        assert_eq!(None, lcm(u64::MAX, u64::MAX - 1));
+        assert_eq!(Some((2, -9, 47)), extended_gcd(240, 46));
+        assert_eq!(None, extended_gcd(i8::MIN, -1));
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(Some(3), mod_inverse(-7, 11));
+        assert_eq!(None, mod_inverse(4, 8));
+        assert_eq!(Some(445), mod_pow(4u32, 13, 497));
+        assert_eq!(Some(0), mod_pow(5, 0, 1));
+        assert_eq!(None, mod_pow(150u8, 2, 200));
+        assert_eq!(None, mod_pow(2, 3, 0));
+        assert_eq!(None, mod_inverse(3, 0));
+    }
+
+    #[test]
+    fn congruences() {
+        assert_eq!(Ok((23, 105)), chinese_remainder([(2, 3), (3, 5), (2, 7)]));
+        assert_eq!(Ok((10, 12)), chinese_remainder([(4, 6), (2, 4)]));
+        assert_eq!(
+            Err(CrtError::Incompatible),
+            chinese_remainder([(1, 6), (2, 4)])
+        );
+        assert_eq!(
+            Err(CrtError::Overflow),
+            chinese_remainder([(1, i64::MAX), (0, i64::MAX - 1)])
+        );
+        assert_eq!(Ok((0, 1)), chinese_remainder::<i32, _>([]));
+        assert_eq!(
+            Err(CrtError::InvalidModulus),
+            chinese_remainder([(1, 3), (0, 0)])
+        );
+    }
+
+    #[test]
+    fn square_roots() {
+        assert_eq!(Some(0), isqrt(0));
+        assert_eq!(Some(3), isqrt(15));
+        assert_eq!(Some(4), isqrt(16u8));
+        assert_eq!(Some(u32::MAX as u64), isqrt(u64::MAX));
+        assert_eq!(None, isqrt(-1));
+    }
+}
@@ -0,0 +1,178 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::grid::Grid;
+
+/// Block font of the letters that puzzles draw on a screen.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Font {
+    pub width: usize,
+    pub height: usize,
+    /// Horizontal distance between the starts of adjacent letters.
+    pub pitch: usize,
+    letters: &'static str,
+    /// One line per row, with the rows of the letters separated by spaces.
+    glyphs: &'static str,
+}
+
+/// Font of 4x6 letters, drawn every 5 columns.
+pub const SMALL_FONT: Font = Font {
+    width: 4,
+    height: 6,
+    pitch: 5,
+    letters: "ABCEFGHIJKLOPRSUZ",
+    glyphs: "\
+    .##. ###. .##. #### #### .##. #..# .### ..## #..# #... .##. ###. ###. .### #..# ####
+    #..# #..# #..# #... #... #..# #..# ..#. ...# #.#. #... #..# #..# #..# #... #..# ...#
+    #..# ###. #... ###. ###. #... #### ..#. ...# ##.. #... #..# #..# #..# #... #..# ..#.
+    #### #..# #... #... #... #.## #..# ..#. ...# #.#. #... #..# ###. ###. .##. #..# .#..
+    #..# #..# #..# #... #... #..# #..# ..#. #..# #.#. #... #..# #... #.#. ...# #..# #...
+    #..# ###. .##. #### #... .### #..# .### .##. #..# #### .##. #... #..# ###. .##. ####",
+};
+
+/// Font of 6x10 letters, drawn every 8 columns.
+pub const LARGE_FONT: Font = Font {
+    width: 6,
+    height: 10,
+    pitch: 8,
+    letters: "ABCEFGHJKLNPRXZ",
+    glyphs: "\
+    ..##.. #####. .####. ###### ###### .####. #....# ...### #....# #..... #....# #####. #####. #....# ######
+    .#..#. #....# #....# #..... #..... #....# #....# ....#. #...#. #..... ##...# #....# #....# #....# .....#
+    #....# #....# #..... #..... #..... #..... #....# ....#. #..#.. #..... ##...# #....# #....# .#..#. .....#
+    #....# #....# #..... #..... #..... #..... #....# ....#. #.#... #..... #.#..# #....# #....# .#..#. ....#.
+    #....# #####. #..... #####. #####. #..... ###### ....#. ##.... #..... #.#..# #####. #####. ..##.. ...#..
+    ###### #....# #..... #..... #..... #..### #....# ....#. ##.... #..... #..#.# #..... #..#.. ..##.. ..#...
+    #....# #....# #..... #..... #..... #....# #....# ....#. #.#... #..... #..#.# #..... #...#. .#..#. .#....
+    #....# #....# #..... #..... #..... #....# #....# #...#. #..#.. #..... #...## #..... #...#. .#..#. #.....
+    #....# #....# #....# #..... #..... #...## #....# #...#. #...#. #..... #...## #..... #....# #....# #.....
+    #....# #####. .####. ###### #..... .###.# #....# .###.. #....# ###### #....# #..... #....# #....# ######",
+};
+
+impl Font {
+    /// Whether the pixels of a letter are lit, row by row.
+    fn get_glyph(&self, index: usize) -> impl Iterator<Item = bool> + '_ {
+        let start = index * (self.width + 1);
+        self.glyphs.lines().flat_map(move |row| {
+            row.trim()[start..start + self.width]
+                .chars()
+                .map(|pixel| pixel == '#')
+        })
+    }
+
+    /// Letter drawn with its top left corner at `(x, 0)`, `None` if it is not one of the font.
+    fn get_letter(&self, pixels: &Grid<bool>, x: usize) -> Option<char> {
+        let drawn = (0..self.height)
+            .flat_map(|y| (x..x + self.width).map(move |x| (x, y)))
+            .map(|position| pixels[position]);
+        let index =
+            (0..self.letters.len()).find(|index| self.get_glyph(*index).eq(drawn.clone()))?;
+        self.letters.chars().nth(index)
+    }
+}
+
+/// Why a screen could not be read.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum OcrError {
+    /// No font has letters of the screen's height.
+    UnsupportedHeight(usize),
+    /// The letter starting at a column is not one of the font.
+    UnknownLetter { column: usize },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::UnsupportedHeight(height) => {
+                write!(f, "no font has letters that are {height} pixels high")
+            }
+            OcrError::UnknownLetter { column } => {
+                write!(f, "unknown letter at column {}", column + 1)
+            }
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+/// [Optical character recognition](https://en.wikipedia.org/wiki/Optical_character_recognition)
+///
+/// Reads the letters of a screen in the font matching its height, with the first letter starting
+/// in the leftmost column and the others following every [`Font::pitch`] columns.
+pub fn recognize(pixels: &Grid<bool>) -> Result<String, OcrError> {
+    match pixels.height() {
+        6 => recognize_with(pixels, &SMALL_FONT),
+        10 => recognize_with(pixels, &LARGE_FONT),
+        height => Err(OcrError::UnsupportedHeight(height)),
+    }
+}
+
+/// Reads the letters of a screen in the given font, see [`recognize`].
+pub fn recognize_with(pixels: &Grid<bool>, font: &Font) -> Result<String, OcrError> {
+    if pixels.height() != font.height {
+        return Err(OcrError::UnsupportedHeight(pixels.height()));
+    }
+    (0..pixels.width())
+        .step_by(font.pitch)
+        .take_while(|x| x + font.width <= pixels.width())
+        .map(|x| {
+            font.get_letter(pixels, x)
+                .ok_or(OcrError::UnknownLetter { column: x })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{recognize, OcrError};
+    use crate::grid::Grid;
+
+    fn parse(screen: &str) -> Grid<bool> {
+        Grid::from_str(screen, |c| c == '#').unwrap()
+    }
+
+    #[test]
+    fn small_letters() {
+        let screen = parse(
+            "\
+###..#....####.####.####.#.....##..####.
+#..#.#....#.......#.#....#....#..#.#....
+#..#.#....###....#..###..#....#....###..
+###..#....#.....#...#....#....#.##.#....
+#.#..#....#....#....#....#....#..#.#....
+#..#.####.####.####.#....####..###.####.",
+        );
+        assert_eq!(Ok("RLEZFLGE".to_owned()), recognize(&screen));
+    }
+
+    #[test]
+    fn large_letters() {
+        let screen = parse(
+            "\
+#....#..#....#
+#....#..##...#
+.#..#...##...#
+.#..#...#.#..#
+..##....#.#..#
+..##....#..#.#
+.#..#...#..#.#
+.#..#...#...##
+#....#..#...##
+#....#..#....#",
+        );
+        assert_eq!(Ok("XN".to_owned()), recognize(&screen));
+    }
+
+    #[test]
+    fn unreadable_screens() {
+        let screen = parse("##..##..\n##..##..\n..##..##\n..##..##\n##..##..\n##..##..");
+        assert_eq!(
+            Err(OcrError::UnknownLetter { column: 0 }),
+            recognize(&screen)
+        );
+        assert_eq!(
+            Err(OcrError::UnsupportedHeight(2)),
+            recognize(&parse("#\n#"))
+        );
+    }
+}
@@ -0,0 +1,540 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::iter::{Enumerate, Peekable};
+use std::path::PathBuf;
+use std::str::{FromStr, SplitInclusive};
+
+use crate::grid::Grid;
+
+/// Input that could not be parsed, with the position where parsing failed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    /// File the input was read from, if known.
+    pub file: Option<PathBuf>,
+    /// Zero-based index of the line.
+    pub line: usize,
+    /// Zero-based index of the character in the line.
+    pub column: usize,
+    /// The line that could not be parsed.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn with_file<P: Into<PathBuf>>(self, file: P) -> Self {
+        Self {
+            file: Some(file.into()),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    /// Renders the position and message, followed by the offending line with the column marked.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (line, column) = (self.line + 1, self.column + 1);
+        match &self.file {
+            Some(file) => write!(f, "{}:{line}:{column}: ", file.display())?,
+            None => write!(f, "line {line}, column {column}: ")?,
+        }
+        write!(f, "{}", self.message)?;
+        if !self.text.is_empty() {
+            write!(f, "\n    {}\n    {}^", self.text, " ".repeat(self.column))?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Position in a text that parsers consume from the front.
+///
+/// Parsers either consume what they recognized and return it, or fail with a [`ParseError`]
+/// pointing at where they are, so they can be chained with `?`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Cursor<'a> {
+    input: &'a str,
+    position: usize,
+    /// Index of the first line of `input` in the whole text, for error positions.
+    first_line: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self::with_first_line(input, 0)
+    }
+
+    /// Cursor past all of `input`, for errors about the input as a whole.
+    pub fn at_end_of(input: &'a str) -> Self {
+        let mut cursor = Self::new(input);
+        cursor.position = input.len();
+        cursor
+    }
+
+    fn with_first_line(input: &'a str, first_line: usize) -> Self {
+        Self {
+            input,
+            position: 0,
+            first_line,
+        }
+    }
+
+    /// The text that has not been consumed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    /// Zero-based line and column of the current position.
+    pub fn position(&self) -> (usize, usize) {
+        let consumed = &self.input[..self.position];
+        let line_start = consumed.rfind('\n').map_or(0, |index| index + 1);
+        (
+            self.first_line + consumed.matches('\n').count(),
+            consumed[line_start..].chars().count(),
+        )
+    }
+
+    /// Error at the current position.
+    pub fn error<M: Into<String>>(&self, message: M) -> ParseError {
+        let (line, column) = self.position();
+        let line_start = self.input[..self.position]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        let line_end = self
+            .rest()
+            .find('\n')
+            .map_or(self.input.len(), |len| self.position + len);
+        let text = &self.input[line_start..line_end];
+        ParseError {
+            file: None,
+            line,
+            column,
+            text: text.strip_suffix('\r').unwrap_or(text).to_owned(),
+            message: message.into(),
+        }
+    }
+
+    fn advance(&mut self, len: usize) -> &'a str {
+        let consumed = &self.rest()[..len];
+        self.position += len;
+        consumed
+    }
+
+    /// Consumes `tag` if the text continues with it.
+    pub fn try_tag(&mut self, tag: &str) -> bool {
+        let found = self.rest().starts_with(tag);
+        if found {
+            self.advance(tag.len());
+        }
+        found
+    }
+
+    pub fn tag(&mut self, tag: &str) -> Result<(), ParseError> {
+        if self.try_tag(tag) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected {tag:?}")))
+        }
+    }
+
+    /// Consumes the first of the tags that the text continues with, returning its value.
+    pub fn choice<T: Copy>(&mut self, options: &[(&str, T)]) -> Result<T, ParseError> {
+        for &(tag, value) in options {
+            if self.try_tag(tag) {
+                return Ok(value);
+            }
+        }
+        let tags = options.iter().map(|(tag, _)| format!("{tag:?}"));
+        Err(self.error(format!(
+            "expected one of {}",
+            tags.collect::<Vec<_>>().join(", ")
+        )))
+    }
+
+    /// Skips spaces and tabs, but not line breaks.
+    pub fn skip_spaces(&mut self) {
+        let rest = self.rest();
+        self.advance(rest.len() - rest.trim_start_matches([' ', '\t']).len());
+    }
+
+    /// Consumes a decimal integer with an optional sign.
+    pub fn integer<T>(&mut self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let rest = self.rest();
+        let sign_len = usize::from(rest.starts_with(['-', '+']));
+        let digits_len = rest[sign_len..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign_len);
+        if digits_len == 0 {
+            return Err(self.error("expected integer"));
+        }
+        let text = &rest[..sign_len + digits_len];
+        let value = text
+            .parse()
+            .map_err(|error| self.error(format!("invalid integer {text:?}: {error}")))?;
+        self.advance(text.len());
+        Ok(value)
+    }
+
+    /// Consumes characters as long as they satisfy the predicate, possibly none.
+    pub fn take_while<P: FnMut(char) -> bool>(&mut self, mut predicate: P) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.advance(len)
+    }
+
+    /// Consumes all characters up to the next whitespace, of which there has to be at least one.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        let rest = self.rest();
+        let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("expected word"));
+        }
+        Ok(self.advance(len))
+    }
+
+    /// Consumes the text up to and including `delimiter`, returning the text before it.
+    pub fn until(&mut self, delimiter: &str) -> Result<&'a str, ParseError> {
+        let Some(len) = self.rest().find(delimiter) else {
+            return Err(self.error(format!("expected {delimiter:?}")));
+        };
+        let consumed = self.advance(len);
+        self.advance(delimiter.len());
+        Ok(consumed)
+    }
+
+    /// Consumes a line break, which may be `\r\n`.
+    pub fn newline(&mut self) -> Result<(), ParseError> {
+        if self.try_tag("\n") || self.try_tag("\r\n") {
+            Ok(())
+        } else {
+            Err(self.error("expected end of line"))
+        }
+    }
+
+    /// Consumes the rest of the current line and its line break, returning the line without it.
+    pub fn line(&mut self) -> Result<&'a str, ParseError> {
+        if self.is_at_end() {
+            return Err(self.error("expected line"));
+        }
+        let rest = self.rest();
+        let len = rest.find('\n').unwrap_or(rest.len());
+        let line = self.advance(len);
+        self.try_tag("\n");
+        Ok(line.strip_suffix('\r').unwrap_or(line))
+    }
+
+    /// Consumes a line of the form `key<separator>value`, returning both without surrounding
+    /// whitespace.
+    pub fn key_value(&mut self, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+        let start = *self;
+        let line = self.line()?;
+        let Some((key, value)) = line.split_once(separator) else {
+            return Err(start.error(format!("expected {separator:?}")));
+        };
+        Ok((key.trim(), value.trim()))
+    }
+
+    /// Consumes one or more items separated by `separator`.
+    pub fn separated<T, F>(&mut self, separator: &str, mut item: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(&mut Self) -> Result<T, ParseError>,
+    {
+        let mut items = vec![item(self)?];
+        while self.try_tag(separator) {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Consumes the rest of the current line, returning all integers in it.
+    ///
+    /// A `-` is taken as sign unless it follows a digit, so `x=-3` is `-3` but `2-4` is `2` and `4`.
+    pub fn integers<T>(&mut self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let mut integers = vec![];
+        let mut previous = None;
+        while let Some(c) = self.rest().chars().next().filter(|c| *c != '\n') {
+            let is_sign = c == '-'
+                && !previous.is_some_and(|p: char| p.is_ascii_digit())
+                && self.rest()[1..].starts_with(|c: char| c.is_ascii_digit());
+            if c.is_ascii_digit() || is_sign {
+                integers.push(self.integer()?);
+                previous = Some('0');
+            } else {
+                self.advance(c.len_utf8());
+                previous = Some(c);
+            }
+        }
+        self.try_tag("\n");
+        Ok(integers)
+    }
+
+    /// Parses every remaining line with `parse_line`, which has to consume all of it.
+    ///
+    /// Empty lines are skipped.
+    pub fn lines<T, F>(&mut self, mut parse_line: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+    {
+        let mut items = vec![];
+        let (mut line_index, _) = self.position();
+        while !self.is_at_end() {
+            let text = self.line()?;
+            if !text.is_empty() {
+                let mut line = Cursor::with_first_line(text, line_index);
+                items.push(parse_line(&mut line)?);
+                line.end()?;
+            }
+            line_index += 1;
+        }
+        Ok(items)
+    }
+
+    /// Consumes lines up to the next blank line as rows of a grid, mapping every character to a
+    /// cell. Fails on characters that `f` returns `None` for and on lines of different lengths.
+    pub fn grid<T, F>(&mut self, mut f: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut cells = vec![];
+        while !self.is_at_end() && !self.rest().starts_with(['\n', '\r']) {
+            let start = *self;
+            let line = self.line()?;
+            let mut line_cursor = start;
+            for c in line.chars() {
+                let cell = f(c).ok_or_else(|| line_cursor.error(format!("unexpected {c:?}")))?;
+                cells.push(cell);
+                line_cursor.advance(c.len_utf8());
+            }
+            let line_width = line.chars().count();
+            match width {
+                None => width = Some(line_width),
+                Some(expected) if expected != line_width => {
+                    return Err(
+                        start.error(format!("line has {line_width} cells, expected {expected}"))
+                    )
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(Grid::from_vec(width.unwrap_or_default(), cells))
+    }
+
+    /// Succeeds if only whitespace is left.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        let rest = self.rest();
+        let len = rest.len() - rest.trim_start().len();
+        self.advance(len);
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.error("expected end of input"))
+        }
+    }
+}
+
+/// Parses every line of `input` with `parse_line`, see [`Cursor::lines`].
+pub fn parse_lines<T, F>(input: &str, parse_line: F) -> Result<Vec<T>, ParseError>
+where
+    F: for<'a> FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+{
+    Cursor::new(input).lines(parse_line)
+}
+
+/// All integers in `text`, see [`Cursor::integers`].
+pub fn integers<T>(text: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut cursor = Cursor::new(text);
+    let mut integers = vec![];
+    while !cursor.is_at_end() {
+        integers.extend(cursor.integers()?);
+    }
+    Ok(integers)
+}
+
+/// Blocks of lines separated by one or more blank lines, like the sections of an input.
+///
+/// Blank lines at the start and end, including `\r\n` line breaks, are skipped. Each block keeps
+/// its original position for errors, and its lines keep their indentation.
+#[derive(Debug, Clone)]
+pub struct Blocks<'a> {
+    input: &'a str,
+    lines: Peekable<Enumerate<SplitInclusive<'a, char>>>,
+    offset: usize,
+}
+
+/// Splits `input` into [`Blocks`].
+pub fn blocks(input: &str) -> Blocks<'_> {
+    Blocks {
+        input,
+        lines: input.split_inclusive('\n').enumerate().peekable(),
+        offset: 0,
+    }
+}
+
+impl<'a> Blocks<'a> {
+    /// Parses the next block with `parse_block`, which has to consume all of it.
+    ///
+    /// Meant for inputs made of sections with different contents, to be parsed in order.
+    pub fn next_parsed<T, F>(&mut self, parse_block: F) -> Result<T, ParseError>
+    where
+        F: FnOnce(&mut Cursor<'a>) -> Result<T, ParseError>,
+    {
+        let Some(mut block) = self.next() else {
+            let mut end = Cursor::new(self.input);
+            end.advance(self.input.len());
+            return Err(end.error("expected another block"));
+        };
+        let value = parse_block(&mut block)?;
+        block.end()?;
+        Ok(value)
+    }
+}
+
+impl<'a> Iterator for Blocks<'a> {
+    type Item = Cursor<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_blank = |(_, line): &(usize, &str)| line.trim().is_empty();
+        while let Some((_, line)) = self.lines.next_if(is_blank) {
+            self.offset += line.len();
+        }
+        let (first_line, _) = *self.lines.peek()?;
+        let start = self.offset;
+        while let Some((_, line)) = self.lines.next_if(|line| !is_blank(line)) {
+            self.offset += line.len();
+        }
+        let block = &self.input[start..self.offset];
+        Some(Cursor::with_first_line(block, first_line))
+    }
+}
+
+/// Parses every block of `input` with `parse_block`, which has to consume all of it.
+pub fn parse_blocks<T, F>(input: &str, mut parse_block: F) -> Result<Vec<T>, ParseError>
+where
+    F: for<'a> FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+{
+    blocks(input)
+        .map(|mut block| {
+            let item = parse_block(&mut block)?;
+            block.end()?;
+            Ok(item)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blocks, integers, parse_blocks, parse_lines, Cursor, ParseError};
+
+    #[test]
+    fn chained_parsers() {
+        let mut cursor = Cursor::new("move 12 from -3 to 4");
+        cursor.tag("move ").unwrap();
+        assert_eq!(Ok(12u32), cursor.integer());
+        cursor.skip_spaces();
+        assert_eq!(Ok("from"), cursor.word());
+        cursor.skip_spaces();
+        assert_eq!(Ok(-3i8), cursor.integer());
+        assert_eq!(Ok(" "), cursor.until("to "));
+        assert_eq!(Ok(4u8), cursor.integer());
+        assert_eq!(Ok(()), cursor.end());
+        assert_eq!(
+            "line 1, column 1: invalid integer \"300\": number too large to fit in target type\n    300\n    ^",
+            Cursor::new("300").integer::<u8>().unwrap_err().to_string()
+        );
+
+        let mut cursor = Cursor::new("abc1");
+        assert_eq!("abc", cursor.take_while(|c| c.is_ascii_alphabetic()));
+        assert_eq!("", cursor.take_while(|c| c.is_ascii_alphabetic()));
+
+        let mut cursor = Cursor::new("items: 1, 2, 3");
+        cursor.tag("items: ").unwrap();
+        assert_eq!(
+            Ok(vec![1, 2, 3]),
+            cursor.separated(", ", |c| c.integer::<u8>())
+        );
+    }
+
+    #[test]
+    fn errors_have_positions() {
+        let error = parse_lines("a 1\na 2\nb 3", |line| {
+            line.tag("a ")?;
+            line.integer::<u32>()
+        });
+        assert_eq!(
+            Err(ParseError {
+                file: None,
+                line: 2,
+                column: 0,
+                text: "b 3".to_string(),
+                message: "expected \"a \"".to_string()
+            }),
+            error
+        );
+        assert_eq!(
+            Err("input.txt:1:3: expected end of input\n    1 x\n      ^".to_string()),
+            parse_lines("1 x", |line| line.integer::<u8>())
+                .map_err(|error| error.with_file("input.txt").to_string())
+        );
+        let mut cursor = Cursor::new("key = value\n#.\n.x\n");
+        assert_eq!(Ok(("key", "value")), cursor.key_value("="));
+        let error = cursor.grid(|c| (c != 'x').then_some(c)).unwrap_err();
+        assert_eq!((2, 1), (error.line, error.column));
+        let end = Cursor::at_end_of("1\n23\n");
+        assert!(end.is_at_end());
+        assert_eq!((2, 0), end.position());
+    }
+
+    #[test]
+    fn integers_and_blocks() {
+        assert_eq!(Ok(vec![2, 4, -3, 10]), integers::<i64>("2-4 x=-3, y=10"));
+        assert!(integers::<u8>("300").is_err());
+
+        let input = "\nab\ncd\n\n\n#.\n.#\n\n";
+        let mut blocks = blocks(input);
+        let mut first = blocks.next().unwrap();
+        assert_eq!("ab\ncd\n", first.rest());
+        assert_eq!(Ok("ab"), first.line());
+        let mut second = blocks.next().unwrap();
+        assert_eq!((5, 0), second.position());
+        let grid = second.grid(|c| Some(c == '#')).unwrap();
+        assert_eq!((2, 2), (grid.width(), grid.height()));
+        assert!(blocks.next().is_none());
+    }
+
+    #[test]
+    fn sections() {
+        let input = "1\r\n2\r\n\r\n3\r\n\r\n";
+        let sums = parse_blocks(input, |block| {
+            let numbers = block.lines(|line| line.integer::<u32>())?;
+            Ok(numbers.into_iter().sum::<u32>())
+        });
+        assert_eq!(Ok(vec![3, 3]), sums);
+
+        let mut blocks = blocks("a\n\nb c\n");
+        assert_eq!(Ok("a"), blocks.next_parsed(|block| block.line()));
+        let error = blocks.next_parsed(|block| block.word()).unwrap_err();
+        assert_eq!((2, 2), (error.line, error.column));
+        let error = blocks.next_parsed(|block| block.line()).unwrap_err();
+        assert_eq!(
+            (3, 0, "expected another block"),
+            (error.line, error.column, &error.message[..])
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use priority_queue::PriorityQueue;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+mod graph;
+mod trace;
+mod traversal;
+
+pub use graph::{connected_components, find_graph_cycle, label_components, topological_sort};
+pub use trace::{SearchEvent, SearchLog, SearchStats, Tracer, Untraced, EXPLORED, FRONTIER};
+pub use traversal::{
+    bfs, bfs_traced, dfs, dfs_traced, dijkstra, dijkstra_traced, flood_fill, flood_fill_traced,
+    Bfs, Dfs, Dijkstra,
+};
+
+/// Step to a successor, as returned by the successor functions of all searches.
+///
+/// Successor functions return either plain nodes, which cost 1 to reach, or `(node, cost)` pairs.
+/// Searches that do not weigh edges, like [`bfs`], ignore the cost.
+pub trait Edge<N> {
+    fn into_parts(self) -> (N, u32);
+}
+
+impl<N> Edge<N> for N {
+    fn into_parts(self) -> (N, u32) {
+        (self, 1)
+    }
+}
+
+impl<N> Edge<N> for (N, u32) {
+    fn into_parts(self) -> (N, u32) {
+        self
+    }
+}
+
+/// Outcome of a shortest path search: the goal that was reached, if any, and the distances and
+/// shortest paths from the nearest start to every node whose distance is final.
+#[derive(Debug, Clone)]
+pub struct SearchResult<T> {
+    goal: Option<T>,
+    distances: HashMap<T, u32>,
+    predecessors: HashMap<T, T>,
+}
+
+impl<T: Eq + Hash + Copy> SearchResult<T> {
+    /// The first goal reached, which is one of the nearest.
+    pub fn goal(&self) -> Option<T> {
+        self.goal
+    }
+
+    /// Distance to the reached goal.
+    pub fn distance(&self) -> Option<u32> {
+        self.distance_to(&self.goal?)
+    }
+
+    pub fn distance_to(&self, node: &T) -> Option<u32> {
+        self.distances.get(node).copied()
+    }
+
+    /// Distances of all nodes explored before the goal was reached, or of all reachable nodes if
+    /// no goal was reached.
+    pub fn distances(&self) -> &HashMap<T, u32> {
+        &self.distances
+    }
+
+    /// Path from the nearest start to the reached goal, both included.
+    pub fn path(&self) -> Option<Vec<T>> {
+        self.path_to(self.goal?)
+    }
+
+    pub fn path_to(&self, node: T) -> Option<Vec<T>> {
+        if !self.distances.contains_key(&node) {
+            return None;
+        }
+        let mut path = vec![node];
+        while let Some(predecessor) = self.predecessors.get(path.last().unwrap()) {
+            path.push(*predecessor);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// [A* search algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm)
+///
+/// Searches from all `starts` at once until a node satisfying `is_goal` is reached. Expanded nodes
+/// are never reopened, so the estimate has to be consistent: it must not exceed the distance to
+/// the nearest goal, and must not drop by more than the cost of an edge along it. With an estimate
+/// of 0 this is Dijkstra's algorithm, and with a goal that is never reached it computes the
+/// distances to all reachable nodes.
+pub fn a_star<T, S, F, I, G, H>(
+    starts: S,
+    get_successors: F,
+    is_goal: G,
+    goal_distance_estimate: H,
+) -> SearchResult<T>
+where
+    T: Eq + Hash + Copy,
+    S: IntoIterator<Item = T>,
+    F: FnMut(T) -> I,
+    I: IntoIterator,
+    I::Item: Edge<T>,
+    G: FnMut(T) -> bool,
+    H: FnMut(T) -> u32,
+{
+    a_star_traced(
+        starts,
+        get_successors,
+        is_goal,
+        goal_distance_estimate,
+        &mut Untraced,
+    )
+}
+
+/// [`a_star`] reporting every step to a tracer, like a [`SearchLog`] to find out which nodes the
+/// search explored.
+pub fn a_star_traced<T, S, F, I, G, H, R>(
+    starts: S,
+    mut get_successors: F,
+    mut is_goal: G,
+    mut goal_distance_estimate: H,
+    tracer: &mut R,
+) -> SearchResult<T>
+where
+    T: Eq + Hash + Copy,
+    S: IntoIterator<Item = T>,
+    F: FnMut(T) -> I,
+    I: IntoIterator,
+    I::Item: Edge<T>,
+    G: FnMut(T) -> bool,
+    H: FnMut(T) -> u32,
+    R: Tracer<T> + ?Sized,
+{
+    let mut visited_nodes = HashSet::new();
+    let mut open_nodes = PriorityQueue::new();
+    let mut start_distance = HashMap::new();
+    let mut predecessors = HashMap::new();
+    for start in starts {
+        open_nodes.push(start, Reverse(goal_distance_estimate(start)));
+        start_distance.insert(start, 0);
+        let event = SearchEvent::Push {
+            node: start,
+            distance: 0,
+            from: None,
+        };
+        tracer.record(event, open_nodes.len());
+    }
+
+    let mut goal = None;
+    while let Some((current_node, _)) = open_nodes.pop() {
+        visited_nodes.insert(current_node);
+        let current_start_distance = start_distance[&current_node];
+        let event = SearchEvent::Expand {
+            node: current_node,
+            distance: current_start_distance,
+        };
+        tracer.record(event, open_nodes.len());
+        if is_goal(current_node) {
+            goal = Some(current_node);
+            break;
+        }
+
+        for edge in get_successors(current_node) {
+            let (successor, successor_distance) = edge.into_parts();
+            if visited_nodes.contains(&successor) {
+                continue;
+            }
+
+            let successor_start_distance = current_start_distance + successor_distance;
+            let is_queued = match start_distance.get(&successor) {
+                Some(distance) if *distance <= successor_start_distance => continue,
+                Some(_) => true,
+                None => false,
+            };
+
+            start_distance.insert(successor, successor_start_distance);
+            predecessors.insert(successor, current_node);
+
+            let successor_cost = successor_start_distance + goal_distance_estimate(successor);
+            open_nodes.push(successor, Reverse(successor_cost));
+            let event = if is_queued {
+                SearchEvent::Relax {
+                    node: successor,
+                    distance: successor_start_distance,
+                    from: current_node,
+                }
+            } else {
+                SearchEvent::Push {
+                    node: successor,
+                    distance: successor_start_distance,
+                    from: Some(current_node),
+                }
+            };
+            tracer.record(event, open_nodes.len());
+        }
+    }
+
+    // Distances of nodes that are still open might not be the shortest.
+    start_distance.retain(|node, _| visited_nodes.contains(node));
+    predecessors.retain(|node, _| visited_nodes.contains(node));
+    SearchResult {
+        goal,
+        distances: start_distance,
+        predecessors,
+    }
+}
+
+/// Distance from `start` to `goal` using [`a_star`].
+pub fn find_shortest_distance<T, F1, F2>(
+    start: T,
+    goal: T,
+    get_successors: F1,
+    goal_distance_estimate: F2,
+) -> Option<u32>
+where
+    T: Eq + Hash + Copy,
+    F1: Fn(T) -> Vec<(T, u32)>,
+    F2: Fn(T) -> u32,
+{
+    a_star(
+        [start],
+        get_successors,
+        |node| node == goal,
+        goal_distance_estimate,
+    )
+    .distance()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{a_star, a_star_traced, SearchLog, SearchStats};
+
+    /// Weighted edges of a small directed graph.
+    fn get_successors(node: char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 4)],
+            'b' => vec![('c', 1), ('d', 5)],
+            'c' => vec![('d', 1)],
+            'x' => vec![('d', 10)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn path_to_goal() {
+        let result = a_star(['a'], get_successors, |node| node == 'd', |_| 0);
+        assert_eq!(Some('d'), result.goal());
+        assert_eq!(Some(3), result.distance());
+        assert_eq!(Some(vec!['a', 'b', 'c', 'd']), result.path());
+    }
+
+    #[test]
+    fn multiple_starts() {
+        let result = a_star(['x', 'c'], get_successors, |node| node == 'd', |_| 0);
+        assert_eq!(Some(vec!['c', 'd']), result.path());
+    }
+
+    #[test]
+    fn distance_map() {
+        let result = a_star(['a'], get_successors, |_| false, |_| 0);
+        assert_eq!(None, result.goal());
+        assert_eq!(4, result.distances().len());
+        assert_eq!(Some(2), result.distance_to(&'c'));
+        assert_eq!(None, result.distance_to(&'x'));
+    }
+
+    #[test]
+    fn traced_steps() {
+        let mut log = SearchLog::new();
+        a_star_traced(['a'], get_successors, |node| node == 'd', |_| 0, &mut log);
+        let stats = SearchStats {
+            pushed: 4,
+            relaxed: 2,
+            expanded: 4,
+            max_queue_len: 2,
+        };
+        assert_eq!(stats, log.stats());
+        assert_eq!(4, log.explored().len());
+        assert!(log.frontier().is_empty());
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use super::{bfs, flood_fill, Edge};
+use crate::geometry::Point2;
+use crate::grid::Grid;
+
+/// Groups the nodes into sets of nodes connected to each other, in order of their first node.
+///
+/// The successors have to be symmetric, i.e. describe an undirected graph.
+pub fn connected_components<N, S, F, I>(nodes: S, mut get_successors: F) -> Vec<Vec<N>>
+where
+    N: Eq + Hash + Copy,
+    S: IntoIterator<Item = N>,
+    F: FnMut(N) -> I,
+    I: IntoIterator,
+    I::Item: Edge<N>,
+{
+    let mut components = vec![];
+    let mut assigned = HashSet::new();
+    for node in nodes {
+        if assigned.contains(&node) {
+            continue;
+        }
+        let component = bfs([node], &mut get_successors)
+            .map(|(node, _)| node)
+            .collect::<Vec<_>>();
+        assigned.extend(component.iter().copied());
+        components.push(component);
+    }
+    components
+}
+
+/// Labels every cell with the index of its region of horizontally and vertically adjacent cells
+/// for which `connected(from, to)` holds, returning the labels and the number of regions.
+///
+/// Regions are numbered in row order of their first cell.
+pub fn label_components<T, F>(grid: &Grid<T>, mut connected: F) -> (Grid<usize>, usize)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut labels = grid.map(|_| None);
+    let mut count = 0;
+    for (x, y) in grid.positions() {
+        if labels[(x, y)].is_some() {
+            continue;
+        }
+        for point in flood_fill(grid, Point2::new(x, y), &mut connected) {
+            labels[point] = Some(count);
+        }
+        count += 1;
+    }
+    (labels.map(|label| label.unwrap()), count)
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum State {
+    InProgress,
+    Done,
+}
+
+/// Orders the nodes reachable from `nodes` so that every node comes before its successors.
+///
+/// Fails with the nodes of a cycle, in order, if there is one.
+pub fn topological_sort<N, S, F, I>(nodes: S, mut get_successors: F) -> Result<Vec<N>, Vec<N>>
+where
+    N: Eq + Hash + Copy,
+    S: IntoIterator<Item = N>,
+    F: FnMut(N) -> I,
+    I: IntoIterator,
+    I::Item: Edge<N>,
+{
+    let mut get_successors = |node| {
+        get_successors(node)
+            .into_iter()
+            .map(|edge| edge.into_parts().0)
+            .collect::<Vec<_>>()
+            .into_iter()
+    };
+
+    let mut states = HashMap::new();
+    let mut order = vec![];
+    for root in nodes {
+        if states.contains_key(&root) {
+            continue;
+        }
+        states.insert(root, State::InProgress);
+        let mut stack = vec![(root, get_successors(root))];
+        while let Some((node, successors)) = stack.last_mut() {
+            let node = *node;
+            let Some(successor) = successors.next() else {
+                states.insert(node, State::Done);
+                order.push(node);
+                stack.pop();
+                continue;
+            };
+            match states.get(&successor) {
+                None => {
+                    states.insert(successor, State::InProgress);
+                    stack.push((successor, get_successors(successor)));
+                }
+                Some(State::InProgress) => {
+                    let start = stack
+                        .iter()
+                        .position(|(node, _)| *node == successor)
+                        .unwrap();
+                    return Err(stack[start..].iter().map(|(node, _)| *node).collect());
+                }
+                Some(State::Done) => {}
+            }
+        }
+    }
+    order.reverse();
+    Ok(order)
+}
+
+/// Finds a cycle among the nodes reachable from `nodes`, returning its nodes in order.
+///
+/// For cycles in the sequence of states of a simulation, see [`crate::cycle::find_cycle`].
+pub fn find_graph_cycle<N, S, F, I>(nodes: S, get_successors: F) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Copy,
+    S: IntoIterator<Item = N>,
+    F: FnMut(N) -> I,
+    I: IntoIterator,
+    I::Item: Edge<N>,
+{
+    topological_sort(nodes, get_successors).err()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{connected_components, find_graph_cycle, label_components, topological_sort};
+    use crate::grid::Grid;
+
+    #[test]
+    fn components() {
+        let successors = |node: u32| match node {
+            1 => vec![2],
+            2 => vec![1],
+            3 => vec![4],
+            4 => vec![3],
+            _ => vec![],
+        };
+        let components = connected_components(1..=5, successors);
+        assert_eq!(vec![vec![1, 2], vec![3, 4], vec![5]], components);
+
+        let grid = Grid::from_str("aab\nbab", |c| c).unwrap();
+        let (labels, count) = label_components(&grid, |from, to| from == to);
+        assert_eq!(3, count);
+        assert_eq!("001\n201", labels.to_string());
+    }
+
+    #[test]
+    fn sort_and_cycles() {
+        let successors = |node: char| match node {
+            'a' => vec!['c', 'b'],
+            'b' => vec!['c'],
+            _ => vec![],
+        };
+        assert_eq!(Ok(vec!['a', 'b', 'c']), topological_sort(['a'], successors));
+        assert_eq!(None, find_graph_cycle(['a', 'b', 'c'], successors));
+
+        let successors = |node: char| match node {
+            'a' => vec!['b'],
+            'b' => vec!['c'],
+            'c' => vec!['b'],
+            _ => vec![],
+        };
+        assert_eq!(Some(vec!['b', 'c']), find_graph_cycle(['a'], successors));
+    }
+}
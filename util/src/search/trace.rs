@@ -0,0 +1,187 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::geometry::Point2;
+use crate::grid::Grid;
+
+/// Step of a search, as reported to a [`Tracer`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SearchEvent<T> {
+    /// A node was queued for the first time, `from` being `None` for the starts.
+    Push {
+        node: T,
+        distance: u32,
+        from: Option<T>,
+    },
+    /// A shorter distance to a queued node was found.
+    Relax { node: T, distance: u32, from: T },
+    /// A node was taken from the queue to look at its successors, with its final distance.
+    Expand { node: T, distance: u32 },
+}
+
+impl<T: Copy> SearchEvent<T> {
+    pub fn node(&self) -> T {
+        match *self {
+            SearchEvent::Push { node, .. }
+            | SearchEvent::Relax { node, .. }
+            | SearchEvent::Expand { node, .. } => node,
+        }
+    }
+}
+
+/// Observer of the steps of a search.
+///
+/// Closures taking the event and the length of the queue after it are tracers as well.
+pub trait Tracer<T> {
+    /// Called for every step, with the number of nodes queued after it.
+    fn record(&mut self, event: SearchEvent<T>, queue_len: usize);
+}
+
+impl<T, F: FnMut(SearchEvent<T>, usize)> Tracer<T> for F {
+    fn record(&mut self, event: SearchEvent<T>, queue_len: usize) {
+        self(event, queue_len)
+    }
+}
+
+/// Tracer ignoring every step, which the searches without one use.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct Untraced;
+
+impl<T> Tracer<T> for Untraced {
+    fn record(&mut self, _event: SearchEvent<T>, _queue_len: usize) {}
+}
+
+/// Counters of the steps of a search.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct SearchStats {
+    pub pushed: usize,
+    pub relaxed: usize,
+    pub expanded: usize,
+    pub max_queue_len: usize,
+}
+
+impl<T> Tracer<T> for SearchStats {
+    fn record(&mut self, event: SearchEvent<T>, queue_len: usize) {
+        match event {
+            SearchEvent::Push { .. } => self.pushed += 1,
+            SearchEvent::Relax { .. } => self.relaxed += 1,
+            SearchEvent::Expand { .. } => self.expanded += 1,
+        }
+        self.max_queue_len = self.max_queue_len.max(queue_len);
+    }
+}
+
+/// Every step of a search in order, with their counters.
+#[derive(Debug, Clone)]
+pub struct SearchLog<T> {
+    events: Vec<SearchEvent<T>>,
+    stats: SearchStats,
+}
+
+impl<T> Default for SearchLog<T> {
+    fn default() -> Self {
+        Self {
+            events: vec![],
+            stats: SearchStats::default(),
+        }
+    }
+}
+
+impl<T: Copy> Tracer<T> for SearchLog<T> {
+    fn record(&mut self, event: SearchEvent<T>, queue_len: usize) {
+        self.stats.record(event, queue_len);
+        self.events.push(event);
+    }
+}
+
+impl<T: Eq + Hash + Copy> SearchLog<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn events(&self) -> &[SearchEvent<T>] {
+        &self.events
+    }
+
+    pub fn stats(&self) -> SearchStats {
+        self.stats
+    }
+
+    /// Nodes that were expanded, whose distances are final.
+    pub fn explored(&self) -> HashSet<T> {
+        self.events
+            .iter()
+            .filter(|event| matches!(event, SearchEvent::Expand { .. }))
+            .map(SearchEvent::node)
+            .collect()
+    }
+
+    /// Nodes that were queued but not expanded when the search ended.
+    pub fn frontier(&self) -> HashSet<T> {
+        let explored = self.explored();
+        self.events
+            .iter()
+            .map(SearchEvent::node)
+            .filter(|node| !explored.contains(node))
+            .collect()
+    }
+}
+
+/// Glyph of the explored cells in [`SearchLog::overlay`].
+pub const EXPLORED: char = 'o';
+/// Glyph of the frontier cells in [`SearchLog::overlay`].
+pub const FRONTIER: char = '+';
+
+impl SearchLog<Point2<usize>> {
+    /// Draws the explored cells as [`EXPLORED`] and the frontier as [`FRONTIER`] over a grid.
+    pub fn overlay(&self, base: &Grid<char>) -> Grid<char> {
+        let mut grid = base.clone();
+        for event in &self.events {
+            draw_event(&mut grid, event);
+        }
+        grid
+    }
+
+    /// Overlays after every expansion, showing how the search progressed.
+    pub fn replay<'a>(&'a self, base: &Grid<char>) -> impl Iterator<Item = Grid<char>> + 'a {
+        let mut grid = base.clone();
+        self.events.iter().filter_map(move |event| {
+            draw_event(&mut grid, event);
+            matches!(event, SearchEvent::Expand { .. }).then(|| grid.clone())
+        })
+    }
+}
+
+fn draw_event(grid: &mut Grid<char>, event: &SearchEvent<Point2<usize>>) {
+    match *event {
+        SearchEvent::Push { node, .. } => grid[node] = FRONTIER,
+        SearchEvent::Relax { .. } => {}
+        SearchEvent::Expand { node, .. } => grid[node] = EXPLORED,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SearchLog;
+    use crate::geometry::Point2;
+    use crate::grid::Grid;
+    use crate::search::a_star_traced;
+
+    #[test]
+    fn overlay_explored_cells() {
+        let grid = Grid::from_str("...\n.#.\n...", |c| c).unwrap();
+        let mut log = SearchLog::new();
+        let start = Point2::new(0, 0);
+        let successors = |point: Point2<usize>| {
+            point
+                .neighbours4()
+                .filter(|neighbour| grid.get(neighbour.x, neighbour.y) == Some(&'.'))
+                .collect::<Vec<_>>()
+        };
+        let goal = |point| point == Point2::new(2, 0);
+        let result = a_star_traced([start], successors, goal, |_| 0, &mut log);
+        assert_eq!(Some(2), result.distance());
+        assert_eq!("ooo\no#.\n+..", log.overlay(&grid).to_string());
+        assert_eq!(4, log.replay(&grid).count());
+    }
+}
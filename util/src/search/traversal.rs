@@ -0,0 +1,446 @@
+use priority_queue::PriorityQueue;
+use std::cmp::Reverse;
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+
+use super::{Edge, SearchEvent, Tracer, Untraced};
+use crate::geometry::Point2;
+use crate::grid::Grid;
+
+/// Iterator over the nodes reachable from the starts in breadth-first order, with the number of
+/// steps to them.
+#[derive(Debug, Clone)]
+pub struct Bfs<N, F, R = Untraced> {
+    queue: VecDeque<(N, u32)>,
+    visited: HashSet<N>,
+    get_successors: F,
+    tracer: R,
+}
+
+/// [Breadth-first search](https://en.wikipedia.org/wiki/Breadth-first_search)
+pub fn bfs<N, S, F>(starts: S, get_successors: F) -> Bfs<N, F>
+where
+    N: Eq + Hash + Copy,
+    S: IntoIterator<Item = N>,
+{
+    start_bfs(starts, get_successors, Untraced)
+}
+
+/// [`bfs`] reporting every step to a tracer.
+pub fn bfs_traced<'a, N, S, F, R>(
+    starts: S,
+    get_successors: F,
+    tracer: &'a mut R,
+) -> Bfs<N, F, impl Tracer<N> + 'a>
+where
+    N: Eq + Hash + Copy,
+    S: IntoIterator<Item = N>,
+    R: Tracer<N> + ?Sized,
+{
+    start_bfs(starts, get_successors, move |event, queue_len| {
+        tracer.record(event, queue_len)
+    })
+}
+
+fn start_bfs<N, S, F, R>(starts: S, get_successors: F, mut tracer: R) -> Bfs<N, F, R>
+where
+    N: Eq + Hash + Copy,
+    S: IntoIterator<Item = N>,
+    R: Tracer<N>,
+{
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if visited.insert(start) {
+            queue.push_back((start, 0));
+            let event = SearchEvent::Push {
+                node: start,
+                distance: 0,
+                from: None,
+            };
+            tracer.record(event, queue.len());
+        }
+    }
+    Bfs {
+        queue,
+        visited,
+        get_successors,
+        tracer,
+    }
+}
+
+impl<N, F, I, R> Iterator for Bfs<N, F, R>
+where
+    N: Eq + Hash + Copy,
+    F: FnMut(N) -> I,
+    I: IntoIterator,
+    I::Item: Edge<N>,
+    R: Tracer<N>,
+{
+    type Item = (N, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, steps) = self.queue.pop_front()?;
+        let event = SearchEvent::Expand {
+            node,
+            distance: steps,
+        };
+        self.tracer.record(event, self.queue.len());
+        for edge in (self.get_successors)(node) {
+            let (successor, _) = edge.into_parts();
+            if self.visited.insert(successor) {
+                self.queue.push_back((successor, steps + 1));
+                let event = SearchEvent::Push {
+                    node: successor,
+                    distance: steps + 1,
+                    from: Some(node),
+                };
+                self.tracer.record(event, self.queue.len());
+            }
+        }
+        Some((node, steps))
+    }
+}
+
+/// Iterator over the nodes reachable from the starts in depth-first pre-order.
+#[derive(Debug, Clone)]
+pub struct Dfs<N, F, R = Untraced> {
+    /// Nodes to visit with their depth, the next one last.
+    stack: Vec<(N, u32)>,
+    visited: HashSet<N>,
+    get_successors: F,
+    tracer: R,
+}
+
+/// [Depth-first search](https://en.wikipedia.org/wiki/Depth-first_search)
+///
+/// Successors are visited in the order they are returned in.
+pub fn dfs<N, S, F>(starts: S, get_successors: F) -> Dfs<N, F>
+where
+    N: Eq + Hash + Copy,
+    S: IntoIterator<Item = N>,
+{
+    start_dfs(starts, get_successors, Untraced)
+}
+
+/// [`dfs`] reporting every step to a tracer.
+///
+/// The distances of the events are the depths in the search tree. Nodes are pushed again for
+/// every visited node they are a successor of until they are visited themselves.
+pub fn dfs_traced<'a, N, S, F, R>(
+    starts: S,
+    get_successors: F,
+    tracer: &'a mut R,
+) -> Dfs<N, F, impl Tracer<N> + 'a>
+where
+    N: Eq + Hash + Copy,
+    S: IntoIterator<Item = N>,
+    R: Tracer<N> + ?Sized,
+{
+    start_dfs(starts, get_successors, move |event, queue_len| {
+        tracer.record(event, queue_len)
+    })
+}
+
+fn start_dfs<N, S, F, R>(starts: S, get_successors: F, mut tracer: R) -> Dfs<N, F, R>
+where
+    N: Eq + Hash + Copy,
+    S: IntoIterator<Item = N>,
+    R: Tracer<N>,
+{
+    let mut stack = vec![];
+    for start in starts {
+        stack.push((start, 0));
+        let event = SearchEvent::Push {
+            node: start,
+            distance: 0,
+            from: None,
+        };
+        tracer.record(event, stack.len());
+    }
+    stack.reverse();
+    Dfs {
+        stack,
+        visited: HashSet::new(),
+        get_successors,
+        tracer,
+    }
+}
+
+impl<N, F, I, R> Iterator for Dfs<N, F, R>
+where
+    N: Eq + Hash + Copy,
+    F: FnMut(N) -> I,
+    I: IntoIterator,
+    I::Item: Edge<N>,
+    R: Tracer<N>,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, depth) = self.stack.pop()?;
+            if !self.visited.insert(node) {
+                continue;
+            }
+            let event = SearchEvent::Expand {
+                node,
+                distance: depth,
+            };
+            self.tracer.record(event, self.stack.len());
+            let successors = (self.get_successors)(node)
+                .into_iter()
+                .map(|edge| edge.into_parts().0)
+                .filter(|successor| !self.visited.contains(successor))
+                .collect::<Vec<_>>();
+            for &successor in successors.iter().rev() {
+                self.stack.push((successor, depth + 1));
+                let event = SearchEvent::Push {
+                    node: successor,
+                    distance: depth + 1,
+                    from: Some(node),
+                };
+                self.tracer.record(event, self.stack.len());
+            }
+            return Some(node);
+        }
+    }
+}
+
+/// Iterator over the nodes reachable from the starts in order of their distance, with that
+/// distance.
+#[derive(Debug, Clone)]
+pub struct Dijkstra<N: Eq + Hash, F, R = Untraced> {
+    open: PriorityQueue<N, Reverse<u32>>,
+    settled: HashSet<N>,
+    get_successors: F,
+    tracer: R,
+}
+
+/// [Dijkstra's algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm)
+///
+/// To get the shortest paths as well, use [`super::a_star`] with an estimate of 0.
+pub fn dijkstra<N, S, F>(starts: S, get_successors: F) -> Dijkstra<N, F>
+where
+    N: Eq + Hash + Copy,
+    S: IntoIterator<Item = N>,
+{
+    start_dijkstra(starts, get_successors, Untraced)
+}
+
+/// [`dijkstra`] reporting every step to a tracer.
+pub fn dijkstra_traced<'a, N, S, F, R>(
+    starts: S,
+    get_successors: F,
+    tracer: &'a mut R,
+) -> Dijkstra<N, F, impl Tracer<N> + 'a>
+where
+    N: Eq + Hash + Copy,
+    S: IntoIterator<Item = N>,
+    R: Tracer<N> + ?Sized,
+{
+    start_dijkstra(starts, get_successors, move |event, queue_len| {
+        tracer.record(event, queue_len)
+    })
+}
+
+fn start_dijkstra<N, S, F, R>(starts: S, get_successors: F, mut tracer: R) -> Dijkstra<N, F, R>
+where
+    N: Eq + Hash + Copy,
+    S: IntoIterator<Item = N>,
+    R: Tracer<N>,
+{
+    let mut open = PriorityQueue::new();
+    for start in starts {
+        if open.push(start, Reverse(0)).is_none() {
+            let event = SearchEvent::Push {
+                node: start,
+                distance: 0,
+                from: None,
+            };
+            tracer.record(event, open.len());
+        }
+    }
+    Dijkstra {
+        open,
+        settled: HashSet::new(),
+        get_successors,
+        tracer,
+    }
+}
+
+impl<N, F, I, R> Iterator for Dijkstra<N, F, R>
+where
+    N: Eq + Hash + Copy,
+    F: FnMut(N) -> I,
+    I: IntoIterator,
+    I::Item: Edge<N>,
+    R: Tracer<N>,
+{
+    type Item = (N, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, Reverse(distance)) = self.open.pop()?;
+        self.settled.insert(node);
+        self.tracer
+            .record(SearchEvent::Expand { node, distance }, self.open.len());
+        for edge in (self.get_successors)(node) {
+            let (successor, cost) = edge.into_parts();
+            if self.settled.contains(&successor) {
+                continue;
+            }
+            let successor_distance = distance + cost;
+            let event = match self
+                .open
+                .push_increase(successor, Reverse(successor_distance))
+            {
+                None => SearchEvent::Push {
+                    node: successor,
+                    distance: successor_distance,
+                    from: Some(node),
+                },
+                // The old distance is given back if it was shorter.
+                Some(Reverse(previous)) if previous > successor_distance => SearchEvent::Relax {
+                    node: successor,
+                    distance: successor_distance,
+                    from: node,
+                },
+                Some(_) => continue,
+            };
+            self.tracer.record(event, self.open.len());
+        }
+        Some((node, distance))
+    }
+}
+
+/// Positions reachable from `start` by horizontal and vertical steps between cells for which
+/// `connected(from, to)` holds, in breadth-first order.
+pub fn flood_fill<T, F>(grid: &Grid<T>, start: Point2<usize>, connected: F) -> Vec<Point2<usize>>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    flood_fill_traced(grid, start, connected, &mut Untraced)
+}
+
+/// [`flood_fill`] reporting every step to a tracer.
+pub fn flood_fill_traced<T, F, R>(
+    grid: &Grid<T>,
+    start: Point2<usize>,
+    mut connected: F,
+    tracer: &mut R,
+) -> Vec<Point2<usize>>
+where
+    F: FnMut(&T, &T) -> bool,
+    R: Tracer<Point2<usize>> + ?Sized,
+{
+    bfs_traced(
+        [start],
+        |point: Point2<usize>| {
+            point
+                .neighbours4()
+                .filter(|neighbour| {
+                    grid.get(neighbour.x, neighbour.y)
+                        .is_some_and(|cell| connected(&grid[point], cell))
+                })
+                .collect::<Vec<_>>()
+        },
+        tracer,
+    )
+    .map(|(point, _)| point)
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{bfs, bfs_traced, dfs, dfs_traced, dijkstra, dijkstra_traced, flood_fill};
+    use super::{flood_fill_traced, SearchEvent};
+    use crate::geometry::Point2;
+    use crate::grid::Grid;
+    use crate::search::{SearchLog, SearchStats};
+
+    fn get_successors(node: u32) -> Vec<u32> {
+        match node {
+            1 => vec![2, 3],
+            2 => vec![4],
+            3 => vec![4, 5],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn traversal_orders() {
+        let bfs = bfs([1], get_successors).collect::<Vec<_>>();
+        assert_eq!(vec![(1, 0), (2, 1), (3, 1), (4, 2), (5, 2)], bfs);
+        let dfs = dfs([1], get_successors).collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 4, 3, 5], dfs);
+    }
+
+    #[test]
+    fn weighted_distances() {
+        let successors = |node: char| match node {
+            'a' => vec![('b', 5), ('c', 1)],
+            'c' => vec![('b', 2)],
+            _ => vec![],
+        };
+        let distances = dijkstra(['a'], successors).collect::<Vec<_>>();
+        assert_eq!(vec![('a', 0), ('c', 1), ('b', 3)], distances);
+    }
+
+    #[test]
+    fn fill_region() {
+        let grid = Grid::from_str("aab\nabb\nccb", |c| c).unwrap();
+        let mut region = flood_fill(&grid, Point2::new(2, 0), |from, to| from == to);
+        region.sort();
+        let expected = [(1, 1), (2, 0), (2, 1), (2, 2)].map(|(x, y)| Point2::new(x, y));
+        assert_eq!(expected.to_vec(), region);
+    }
+
+    #[test]
+    fn traced_traversals() {
+        let mut log = SearchLog::new();
+        assert_eq!(5, bfs_traced([1], get_successors, &mut log).count());
+        let stats = SearchStats {
+            pushed: 5,
+            relaxed: 0,
+            expanded: 5,
+            max_queue_len: 2,
+        };
+        assert_eq!(stats, log.stats());
+
+        let mut log = SearchLog::new();
+        let search = dfs_traced([1], get_successors, &mut log);
+        assert_eq!(vec![1, 2], search.take(2).collect::<Vec<_>>());
+        assert_eq!([3, 4].into_iter().collect::<HashSet<_>>(), log.frontier());
+        let depths = log
+            .events()
+            .iter()
+            .filter_map(|event| match event {
+                SearchEvent::Push { node, distance, .. } => Some((*node, *distance)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(1, 0), (3, 1), (2, 1), (4, 2)], depths);
+
+        let successors = |node: char| match node {
+            'a' => vec![('b', 5), ('c', 1)],
+            'c' => vec![('b', 2)],
+            _ => vec![],
+        };
+        let mut stats = SearchStats::default();
+        dijkstra_traced(['a'], successors, &mut stats).for_each(drop);
+        let expected = SearchStats {
+            pushed: 3,
+            relaxed: 1,
+            expanded: 3,
+            max_queue_len: 2,
+        };
+        assert_eq!(expected, stats);
+
+        let grid = Grid::from_str("aab\nabb\nccb", |c| c).unwrap();
+        let mut stats = SearchStats::default();
+        flood_fill_traced(&grid, Point2::new(2, 0), |from, to| from == to, &mut stats);
+        assert_eq!((4, 4), (stats.pushed, stats.expanded));
+    }
+}
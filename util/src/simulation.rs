@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::cycle::Cycle;
+
+/// State that advances one tick at a time.
+pub trait Simulation {
+    fn step(&mut self);
+
+    /// Whether the simulation has come to an end, after which it is not stepped any more.
+    fn is_finished(&self) -> bool {
+        false
+    }
+}
+
+type Hook<'a, S> = Box<dyn FnMut(usize, &S) + 'a>;
+
+/// Runs a [`Simulation`], counting its steps and calling hooks after every one.
+///
+/// With snapshots enabled, the state can be rewound to an earlier step, which restores the
+/// nearest snapshot before it and replays the steps from there without calling the hooks.
+pub struct Driver<'a, S> {
+    state: S,
+    steps: usize,
+    hooks: Vec<Hook<'a, S>>,
+    snapshot_interval: usize,
+    /// Snapshots in order of their steps.
+    snapshots: Vec<(usize, S)>,
+    clone: Option<fn(&S) -> S>,
+}
+
+impl<'a, S: Simulation> Driver<'a, S> {
+    pub fn new(state: S) -> Self {
+        Self {
+            state,
+            steps: 0,
+            hooks: vec![],
+            snapshot_interval: 0,
+            snapshots: vec![],
+            clone: None,
+        }
+    }
+
+    /// Keeps a copy of the state every `interval` steps, starting with the current one.
+    ///
+    /// # Panics
+    ///
+    /// If the interval is 0.
+    pub fn with_snapshots(mut self, interval: usize) -> Self
+    where
+        S: Clone,
+    {
+        assert!(interval > 0, "snapshot interval must be positive");
+        self.snapshot_interval = interval;
+        self.clone = Some(S::clone);
+        self.snapshots = vec![(self.steps, self.state.clone())];
+        self
+    }
+
+    /// Calls `hook` with the number of steps and the state after every step.
+    pub fn on_step<F: FnMut(usize, &S) + 'a>(mut self, hook: F) -> Self {
+        self.hooks.push(Box::new(hook));
+        self
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn into_state(self) -> S {
+        self.state
+    }
+
+    /// Number of steps taken so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// The snapshots with the number of steps they were taken after, in order.
+    pub fn snapshots(&self) -> impl Iterator<Item = (usize, &S)> {
+        self.snapshots.iter().map(|(steps, state)| (*steps, state))
+    }
+
+    /// Advances the state without calling the hooks, returning `false` if it is finished.
+    fn advance(&mut self) -> bool {
+        if self.state.is_finished() {
+            return false;
+        }
+        self.state.step();
+        self.steps += 1;
+        true
+    }
+
+    /// Takes a step, returning `false` if the simulation is finished instead.
+    pub fn step(&mut self) -> bool {
+        if !self.advance() {
+            return false;
+        }
+        if let Some(clone) = self
+            .clone
+            .filter(|_| self.steps.is_multiple_of(self.snapshot_interval))
+        {
+            self.snapshots.push((self.steps, clone(&self.state)));
+        }
+        for hook in &mut self.hooks {
+            hook(self.steps, &self.state);
+        }
+        true
+    }
+
+    /// Steps until the simulation is finished, which never returns if it does not finish, and
+    /// returns the number of steps taken.
+    pub fn run(&mut self) -> usize {
+        let start = self.steps;
+        while self.step() {}
+        self.steps - start
+    }
+
+    /// Takes up to `n` steps, fewer if the simulation finishes, and returns how many were taken.
+    pub fn run_steps(&mut self, n: usize) -> usize {
+        let start = self.steps;
+        while self.steps - start < n && self.step() {}
+        self.steps - start
+    }
+
+    /// Steps until the state satisfies the predicate, which is checked before every step.
+    ///
+    /// Returns `false` if the simulation finished first.
+    pub fn run_until<P: FnMut(&S) -> bool>(&mut self, mut predicate: P) -> bool {
+        loop {
+            if predicate(&self.state) {
+                return true;
+            }
+            if !self.step() {
+                return false;
+            }
+        }
+    }
+
+    /// Restores the state after `steps` steps from the nearest snapshot before it.
+    ///
+    /// Returns `false` without changing the state if there is no such snapshot or the step has not
+    /// been reached yet. Snapshots after the step are dropped.
+    pub fn rewind(&mut self, steps: usize) -> bool {
+        let Some(clone) = self.clone else {
+            return false;
+        };
+        let index = self.snapshots.partition_point(|(other, _)| *other <= steps);
+        if steps > self.steps || index == 0 {
+            return false;
+        }
+        self.snapshots.truncate(index);
+        let (snapshot_steps, snapshot) = &self.snapshots[index - 1];
+        self.state = clone(snapshot);
+        self.steps = *snapshot_steps;
+        while self.steps < steps && self.advance() {}
+        true
+    }
+
+    /// Steps until a state repeats, comparing them by `get_key`, and returns the cycle in terms of
+    /// the total number of steps. Returns `None` if the simulation finished first.
+    pub fn find_cycle<K, F>(&mut self, mut get_key: F) -> Option<Cycle>
+    where
+        K: Eq + Hash,
+        F: FnMut(&S) -> K,
+    {
+        let mut seen = HashMap::new();
+        loop {
+            if let Some(first) = seen.insert(get_key(&self.state), self.steps) {
+                return Some(Cycle {
+                    prefix_length: first,
+                    length: self.steps - first,
+                });
+            }
+            if !self.step() {
+                return None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Driver, Simulation};
+    use crate::cycle::Cycle;
+
+    /// Counter that finishes at a limit, if there is one.
+    #[derive(Debug, Clone, Eq, PartialEq)]
+    struct Counter {
+        value: u32,
+        limit: Option<u32>,
+    }
+
+    impl Simulation for Counter {
+        fn step(&mut self) {
+            self.value += 1;
+        }
+
+        fn is_finished(&self) -> bool {
+            self.limit == Some(self.value)
+        }
+    }
+
+    fn counter(limit: Option<u32>) -> Counter {
+        Counter { value: 0, limit }
+    }
+
+    #[test]
+    fn run_and_hooks() {
+        let mut seen = vec![];
+        let mut driver = Driver::new(counter(Some(5))).on_step(|steps, state: &Counter| {
+            seen.push((steps, state.value));
+        });
+        assert_eq!(2, driver.run_steps(2));
+        assert!(driver.run_until(|state| state.value == 4));
+        assert_eq!(1, driver.run());
+        assert!(!driver.step());
+        assert_eq!(5, driver.steps());
+        drop(driver);
+        assert_eq!(vec![(1, 1), (2, 2), (3, 3), (4, 4), (5, 5)], seen);
+    }
+
+    #[test]
+    fn snapshots_and_rewind() {
+        let mut hook_calls = 0;
+        let mut driver = Driver::new(counter(None))
+            .with_snapshots(3)
+            .on_step(|_, _| hook_calls += 1);
+        driver.run_steps(10);
+        let steps = driver
+            .snapshots()
+            .map(|(steps, _)| steps)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![0, 3, 6, 9], steps);
+
+        assert!(driver.rewind(4));
+        assert_eq!((4, 4), (driver.steps(), driver.state().value));
+        assert_eq!(2, driver.snapshots().count());
+        assert!(!driver.rewind(7));
+        driver.run_steps(3);
+        assert_eq!(3, driver.snapshots().count());
+        drop(driver);
+        assert_eq!(13, hook_calls);
+
+        assert!(!Driver::new(counter(None)).rewind(0));
+    }
+
+    #[derive(Debug)]
+    struct Modular(u32);
+
+    impl Simulation for Modular {
+        fn step(&mut self) {
+            self.0 = (self.0 * 2 + 1) % 10;
+        }
+    }
+
+    #[test]
+    fn cycle_of_states() {
+        // 0, 1, 3, 7, 5, 1, ...
+        let mut driver = Driver::new(Modular(0));
+        let cycle = Cycle {
+            prefix_length: 1,
+            length: 4,
+        };
+        assert_eq!(Some(cycle), driver.find_cycle(|state| state.0));
+        assert_eq!(
+            None,
+            Driver::new(counter(Some(3))).find_cycle(|state| state.value)
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use std::fmt::Display;
+
+use crate::parse::ParseError;
+
+/// Solution of a single puzzle day.
+///
+/// The puzzle input is parsed once into [`Solution::Input`], which both parts then answer from.
+/// Malformed input is reported by `parse`, so the parts can rely on the input being valid.
+pub trait Solution {
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+}
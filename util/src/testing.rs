@@ -0,0 +1,141 @@
+use std::fmt::Display;
+
+use crate::input::{InputResolver, InputSource};
+use crate::Solution;
+
+/// Generates a test per part and input that checks the answer of a [`Solution`].
+///
+/// Inputs are named like on the command line, `input` for the puzzle input and `exampleN` or
+/// `demo` for the examples, and resolved against the day's crate. A missing puzzle input skips
+/// the test, so it passes without the personal inputs checked out.
+///
+/// ```ignore
+/// util::aoc_tests! {
+///     Day01,
+///     part1: example1 => "142", input => "54632";
+///     part2: example2 => "281";
+/// }
+/// ```
+#[macro_export]
+macro_rules! aoc_tests {
+    ($solution:ty, $($part:ident: $($source:ident => $expected:expr),+ $(,)?);+ $(;)?) => {
+        #[cfg(test)]
+        mod aoc_tests {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $(
+                mod $part {
+                    #[allow(unused_imports)]
+                    use super::*;
+
+                    $(
+                        #[test]
+                        fn $source() {
+                            $crate::testing::check_answer::<$solution>(
+                                env!("CARGO_MANIFEST_DIR"),
+                                $crate::__aoc_tests_part!($part),
+                                stringify!($source),
+                                $expected,
+                            );
+                        }
+                    )+
+                }
+            )+
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __aoc_tests_part {
+    (part1) => {
+        1
+    };
+    (part2) => {
+        2
+    };
+    ($other:ident) => {
+        compile_error!(concat!(
+            "expected `part1` or `part2`, found `",
+            stringify!($other),
+            "`"
+        ))
+    };
+}
+
+/// Checks the answer of a part for an input of the day in `day_dir`, used by [`aoc_tests!`].
+///
+/// # Panics
+///
+/// If the answer differs or the input cannot be read or parsed, except for a missing puzzle input.
+pub fn check_answer<S: Solution>(day_dir: &str, part: u8, source: &str, expected: impl Display) {
+    let source = source.parse::<InputSource>().unwrap();
+    if !matches!(source, InputSource::Puzzle | InputSource::Example(_)) {
+        panic!("unsupported input '{source}', expected 'input' or 'exampleN'");
+    }
+    let resolver = InputResolver::new(day_dir);
+    let file = match resolver.resolve(&source) {
+        Ok(file) => file.unwrap(),
+        Err(error) if source == InputSource::Puzzle => {
+            eprintln!("skipping part {part}: {error}");
+            return;
+        }
+        Err(error) => panic!("{error}"),
+    };
+    let input = resolver
+        .read(&source)
+        .unwrap_or_else(|error| panic!("{error}"));
+    let input = S::parse(&input).unwrap_or_else(|error| panic!("{}", error.with_file(&file)));
+    let answer = match part {
+        1 => S::part_one(&input).to_string(),
+        2 => S::part_two(&input).to_string(),
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        expected.to_string(),
+        answer,
+        "part {part} of '{}'",
+        file.display()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_answer;
+    use crate::parse::{self, ParseError};
+    use crate::Solution;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            parse::parse_lines(input, |line| line.integer())
+        }
+
+        fn part_one(numbers: &Self::Input) -> Self::PartOne {
+            numbers.iter().sum()
+        }
+
+        fn part_two(numbers: &Self::Input) -> Self::PartTwo {
+            numbers.len()
+        }
+    }
+
+    #[test]
+    fn missing_puzzle_input_is_skipped() {
+        let day_dir = std::env::temp_dir().join("aoc-util-missing-day");
+        check_answer::<Sum>(day_dir.to_str().unwrap(), 1, "input", 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "example1.txt")]
+    fn missing_example_fails() {
+        let day_dir = std::env::temp_dir().join("aoc-util-missing-day");
+        check_answer::<Sum>(day_dir.to_str().unwrap(), 1, "example1", 0);
+    }
+}
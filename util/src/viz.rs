@@ -0,0 +1,421 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::time::{Duration, Instant};
+
+use crate::grid::{Bounds, Grid, SparseGrid};
+
+mod export;
+
+pub use export::Recorder;
+
+/// Environment variable enabling the visualisations of the days, set to the frame rate or to
+/// `step` to animate them in the terminal, to a `.gif` or `.png` file to record them to, or to a
+/// directory ending in `/` to save their frames in.
+pub const VIZ_VAR: &str = "VIZ";
+
+/// Environment variable to only draw every `n`th frame of the visualisations.
+pub const VIZ_EVERY_VAR: &str = "VIZ_EVERY";
+
+const DEFAULT_FPS: f64 = 30.0;
+
+/// State of a simulation that can be drawn as a grid of characters.
+pub trait RenderState {
+    /// Region to draw, which may change from frame to frame.
+    fn bounds(&self) -> Bounds;
+
+    /// Character of a cell within the bounds, which also is its class to pick a colour by.
+    fn glyph(&self, x: i64, y: i64) -> char;
+}
+
+impl RenderState for Grid<char> {
+    /// An empty grid is drawn as a single free cell, as bounds cannot be empty.
+    fn bounds(&self) -> Bounds {
+        let mut bounds = Bounds::new(0, 0);
+        if self.width() > 0 && self.height() > 0 {
+            bounds.include(self.width() as i64 - 1, self.height() as i64 - 1);
+        }
+        bounds
+    }
+
+    /// Cells outside of the grid are drawn as `.`.
+    fn glyph(&self, x: i64, y: i64) -> char {
+        let (Ok(x), Ok(y)) = (usize::try_from(x), usize::try_from(y)) else {
+            return '.';
+        };
+        self.get(x, y).copied().unwrap_or('.')
+    }
+}
+
+impl RenderState for SparseGrid<char> {
+    fn bounds(&self) -> Bounds {
+        self.bounds().unwrap_or(Bounds::new(0, 0))
+    }
+
+    /// Free cells are drawn as `.`.
+    fn glyph(&self, x: i64, y: i64) -> char {
+        self.get(x, y).copied().unwrap_or('.')
+    }
+}
+
+/// Colour given by its red, green and blue components.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Self = Self::new(0, 0, 0);
+    pub const WHITE: Self = Self::new(255, 255, 255);
+    pub const GRAY: Self = Self::new(128, 128, 128);
+    pub const RED: Self = Self::new(220, 50, 47);
+    pub const GREEN: Self = Self::new(80, 180, 60);
+    pub const BLUE: Self = Self::new(38, 110, 210);
+    pub const YELLOW: Self = Self::new(230, 190, 40);
+    pub const ORANGE: Self = Self::new(230, 120, 30);
+    pub const CYAN: Self = Self::new(40, 180, 190);
+    pub const MAGENTA: Self = Self::new(200, 60, 160);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+}
+
+/// Colours of the cell classes, which are the glyphs of the cells.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Palette {
+    colors: HashMap<char, Color>,
+}
+
+impl Palette {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, glyph: char, color: Color) -> Self {
+        self.colors.insert(glyph, color);
+        self
+    }
+
+    /// Colour of a glyph, `None` for the default colour of the output.
+    pub fn get(&self, glyph: char) -> Option<Color> {
+        self.colors.get(&glyph).copied()
+    }
+}
+
+/// Snapshot of a [`RenderState`], with `glyphs[(0, 0)]` being the top left cell of the bounds.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Frame {
+    pub bounds: Bounds,
+    pub glyphs: Grid<char>,
+}
+
+impl Frame {
+    pub fn capture<S: RenderState + ?Sized>(state: &S) -> Self {
+        let bounds = state.bounds();
+        let glyphs = (bounds.min_y..=bounds.max_y)
+            .flat_map(|y| (bounds.min_x..=bounds.max_x).map(move |x| (x, y)))
+            .map(|(x, y)| state.glyph(x, y))
+            .collect();
+        Self {
+            bounds,
+            glyphs: Grid::from_vec(bounds.width() as usize, glyphs),
+        }
+    }
+}
+
+/// Destination of the frames of an animation.
+pub trait FrameSink {
+    fn draw_frame(&mut self, frame: &Frame) -> io::Result<()>;
+
+    fn draw(&mut self, state: &dyn RenderState) -> io::Result<()> {
+        self.draw_frame(&Frame::capture(state))
+    }
+
+    /// Region that all frames will lie within, for outputs that need to know it before the first
+    /// frame.
+    fn set_canvas(&mut self, _canvas: Bounds) {}
+
+    /// Completes the animation after the last frame.
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Passes on only every `n`th frame to another sink, starting with the first one, without
+/// capturing the others.
+#[derive(Debug)]
+pub struct Every<S> {
+    sink: S,
+    n: usize,
+    drawn: usize,
+}
+
+impl<S: FrameSink> Every<S> {
+    /// # Panics
+    ///
+    /// If `n` is 0.
+    pub fn new(sink: S, n: usize) -> Self {
+        assert!(n > 0, "frame interval must be positive");
+        Self { sink, n, drawn: 0 }
+    }
+
+    pub fn into_inner(self) -> S {
+        self.sink
+    }
+
+    /// Whether the next frame is passed on, counting it as drawn.
+    fn take_turn(&mut self) -> bool {
+        let turn = self.drawn.is_multiple_of(self.n);
+        self.drawn += 1;
+        turn
+    }
+}
+
+impl<S: FrameSink> FrameSink for Every<S> {
+    fn draw_frame(&mut self, frame: &Frame) -> io::Result<()> {
+        if self.take_turn() {
+            self.sink.draw_frame(frame)?;
+        }
+        Ok(())
+    }
+
+    fn draw(&mut self, state: &dyn RenderState) -> io::Result<()> {
+        if self.take_turn() {
+            self.sink.draw(state)?;
+        }
+        Ok(())
+    }
+
+    fn set_canvas(&mut self, canvas: Bounds) {
+        self.sink.set_canvas(canvas);
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.sink.finish()
+    }
+}
+
+/// Output selected by [`VIZ_VAR`] and [`VIZ_EVERY_VAR`], `None` if it is not set or invalid,
+/// which is warned about.
+///
+/// Terminal colours are turned off by the `NO_COLOR` convention.
+pub fn from_env(palette: Palette) -> Option<Box<dyn FrameSink>> {
+    let viz = std::env::var(VIZ_VAR).ok()?;
+    let every = match std::env::var(VIZ_EVERY_VAR) {
+        Err(_) => 1,
+        Ok(every) => match every.parse() {
+            Ok(every) if every > 0 => every,
+            _ => {
+                eprintln!(
+                    "warning: ignoring {VIZ_EVERY_VAR}={every:?}, expected a positive number"
+                );
+                1
+            }
+        },
+    };
+    if viz.ends_with('/') {
+        let recorder = Recorder::frames(viz).palette(palette);
+        return Some(Box::new(Every::new(recorder, every)));
+    }
+    if let Ok(recorder) = Recorder::new(&viz) {
+        return Some(Box::new(Every::new(recorder.palette(palette), every)));
+    }
+    let terminal = Terminal::new()
+        .palette(palette)
+        .colors(std::env::var_os("NO_COLOR").is_none());
+    match viz.as_str() {
+        "step" => Some(Box::new(Every::new(terminal.step_through(true), every))),
+        fps => match fps.parse() {
+            Ok(fps) => Some(Box::new(Every::new(terminal.fps(fps), every))),
+            Err(_) => {
+                eprintln!(
+                    "warning: ignoring {VIZ_VAR}={viz:?}, expected a frame rate, \"step\", a \
+                     .gif or .png file or a directory ending in /"
+                );
+                None
+            }
+        },
+    }
+}
+
+/// Animation drawn to a terminal, redrawing every frame in place with ANSI escape codes.
+///
+/// Frames go to standard error by default, keeping them apart from the answers on standard output.
+///
+/// Frames are throttled to the frame rate, or wait for Enter on standard input when stepping
+/// through them.
+#[derive(Debug)]
+pub struct Terminal<W: Write = io::Stderr> {
+    out: W,
+    palette: Palette,
+    frame_time: Duration,
+    step_through: bool,
+    colors: bool,
+    last_frame: Option<Instant>,
+}
+
+impl Terminal {
+    pub fn new() -> Self {
+        Self::with_writer(io::stderr())
+    }
+}
+
+impl Default for Terminal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: Write> Terminal<W> {
+    pub fn with_writer(out: W) -> Self {
+        Self {
+            out,
+            palette: Palette::new(),
+            frame_time: Duration::from_secs_f64(1.0 / DEFAULT_FPS),
+            step_through: false,
+            colors: true,
+            last_frame: None,
+        }
+    }
+
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Frames per second, with frames drawn as fast as possible if it is not positive.
+    pub fn fps(mut self, fps: f64) -> Self {
+        self.frame_time = if fps > 0.0 {
+            Duration::from_secs_f64(1.0 / fps)
+        } else {
+            Duration::ZERO
+        };
+        self
+    }
+
+    /// Waits for Enter after every frame instead of for the frame time.
+    pub fn step_through(mut self, step_through: bool) -> Self {
+        self.step_through = step_through;
+        self
+    }
+
+    pub fn colors(mut self, colors: bool) -> Self {
+        self.colors = colors;
+        self
+    }
+}
+
+impl<W: Write> FrameSink for Terminal<W> {
+    fn draw_frame(&mut self, frame: &Frame) -> io::Result<()> {
+        if let Some(last_frame) = self.last_frame.filter(|_| !self.step_through) {
+            std::thread::sleep(self.frame_time.saturating_sub(last_frame.elapsed()));
+        }
+        let mut output = String::new();
+        if self.last_frame.is_none() {
+            // Hide the cursor and clear the screen.
+            output.push_str("\x1b[?25l\x1b[2J");
+        }
+        output.push_str("\x1b[H");
+        for row in frame.glyphs.rows() {
+            let mut current = None;
+            for &glyph in row {
+                let color = self.palette.get(glyph).filter(|_| self.colors);
+                if color != current {
+                    match color {
+                        Some(Color { r, g, b }) => output += &format!("\x1b[38;2;{r};{g};{b}m"),
+                        None => output.push_str("\x1b[0m"),
+                    }
+                    current = color;
+                }
+                output.push(glyph);
+            }
+            if current.is_some() {
+                output.push_str("\x1b[0m");
+            }
+            // Clear what is left of a wider previous frame.
+            output.push_str("\x1b[K\n");
+        }
+        output.push_str("\x1b[J");
+        self.out.write_all(output.as_bytes())?;
+        self.out.flush()?;
+
+        if self.step_through {
+            let mut line = String::new();
+            if io::stdin().lock().read_line(&mut line)? == 0 {
+                self.step_through = false;
+            }
+        }
+        self.last_frame = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Shows the cursor again if any frame was drawn.
+    fn finish(&mut self) -> io::Result<()> {
+        if self.last_frame.take().is_some() {
+            self.out.write_all(b"\x1b[0m\x1b[?25h")?;
+            self.out.flush()?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Drop for Terminal<W> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Color, Every, Frame, FrameSink, Palette, Terminal};
+    use crate::grid::{Bounds, Grid, SparseGrid};
+
+    #[test]
+    fn capture_sparse_grid() {
+        let grid = [((1, -1), '#'), ((2, 0), 'o')]
+            .into_iter()
+            .collect::<SparseGrid<_>>();
+        let frame = Frame::capture(&grid);
+        let mut bounds = Bounds::new(1, -1);
+        bounds.include(2, 0);
+        assert_eq!(bounds, frame.bounds);
+        assert_eq!("#.\n.o", frame.glyphs.to_string());
+
+        let frame = Frame::capture(&Grid::new(0, 0, '#'));
+        assert_eq!(Bounds::new(0, 0), frame.bounds);
+        assert_eq!(".", frame.glyphs.to_string());
+    }
+
+    #[test]
+    fn draw_with_colors() {
+        let grid = [((0, 0), '#'), ((1, 0), '#'), ((0, 1), 'o')]
+            .into_iter()
+            .collect::<SparseGrid<_>>();
+        let mut output = vec![];
+        {
+            let palette = Palette::new().with('#', Color::new(1, 2, 3));
+            let mut terminal = Terminal::with_writer(&mut output).palette(palette);
+            terminal.draw(&grid).unwrap();
+        }
+        let expected = "\x1b[?25l\x1b[2J\x1b[H\
+                        \x1b[38;2;1;2;3m##\x1b[0m\x1b[K\n\
+                        o.\x1b[K\n\
+                        \x1b[J\x1b[0m\x1b[?25h";
+        assert_eq!(expected, String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn draw_every_other_frame() {
+        let grid = [((0, 0), '#')].into_iter().collect::<SparseGrid<_>>();
+        let mut sink = Every::new(Terminal::with_writer(vec![]).fps(0.0), 2);
+        for _ in 0..3 {
+            sink.draw(&grid).unwrap();
+        }
+        let mut terminal = sink.into_inner();
+        terminal.finish().unwrap();
+        let output = String::from_utf8(std::mem::take(&mut terminal.out)).unwrap();
+        assert_eq!(2, output.matches("\x1b[H").count());
+    }
+}
@@ -0,0 +1,487 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::{Color, Frame, FrameSink, Palette, DEFAULT_FPS};
+use crate::grid::Bounds;
+
+/// Length of the signature that PNG files start with, which is followed by the chunks.
+const PNG_SIGNATURE_LEN: u64 = 8;
+
+/// Records frames as they are drawn to an animated GIF or PNG, or to a directory of PNG images.
+///
+/// Every cell becomes a square of `scale` pixels in the colour of its glyph. Glyphs without a
+/// colour in the palette are drawn in the background colour, so free cells can be left out of it.
+/// All frames are drawn on one canvas, which is the bounds of the first frame unless it is set up
+/// front, and cells outside of it are cut off. Animations only store the region that changed from
+/// one frame to the next.
+pub struct Recorder {
+    palette: Palette,
+    background: Color,
+    scale: u32,
+    frame_time: Duration,
+    canvas: Option<Bounds>,
+    path: PathBuf,
+    format: Format,
+    encoder: Option<Encoder>,
+    /// Colours of the cells of the last recorded frame, row by row.
+    previous: Vec<Color>,
+    recorded: usize,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Format {
+    Gif,
+    Png,
+    Frames,
+}
+
+enum Encoder {
+    Gif {
+        encoder: gif::Encoder<BufWriter<File>>,
+        indices: HashMap<Color, u8>,
+    },
+    /// The file is kept to write the number of frames once it is known.
+    Png {
+        writer: png::Writer<BufWriter<File>>,
+        file: File,
+    },
+    Frames,
+}
+
+impl Recorder {
+    /// Recorder of an animated GIF or PNG, depending on the extension of the path.
+    pub fn new<P: Into<PathBuf>>(path: P) -> io::Result<Self> {
+        let path = path.into();
+        let format = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("gif") => Format::Gif,
+            Some("png" | "apng") => Format::Png,
+            _ => {
+                let message = format!("'{}' is not a .gif or .png file", path.display());
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, message));
+            }
+        };
+        Ok(Self::with_format(path, format))
+    }
+
+    /// Recorder of every frame as a PNG image `frameNNNNN.png` in a directory.
+    pub fn frames<P: Into<PathBuf>>(dir: P) -> Self {
+        Self::with_format(dir.into(), Format::Frames)
+    }
+
+    fn with_format(path: PathBuf, format: Format) -> Self {
+        Self {
+            palette: Palette::new(),
+            background: Color::BLACK,
+            scale: 4,
+            frame_time: Duration::from_secs_f64(1.0 / DEFAULT_FPS),
+            canvas: None,
+            path,
+            format,
+            encoder: None,
+            previous: vec![],
+            recorded: 0,
+        }
+    }
+
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    pub fn background(mut self, background: Color) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Width and height of a cell in pixels.
+    ///
+    /// # Panics
+    ///
+    /// If the scale is 0.
+    pub fn scale(mut self, scale: u32) -> Self {
+        assert!(scale > 0, "scale must be positive");
+        self.scale = scale;
+        self
+    }
+
+    /// Frames per second of the animations.
+    ///
+    /// # Panics
+    ///
+    /// If the frame rate is not positive.
+    pub fn fps(mut self, fps: f64) -> Self {
+        assert!(fps > 0.0, "frame rate must be positive");
+        self.frame_time = Duration::from_secs_f64(1.0 / fps);
+        self
+    }
+
+    /// Region to record instead of the bounds of the first frame.
+    pub fn canvas(mut self, canvas: Bounds) -> Self {
+        self.canvas = Some(canvas);
+        self
+    }
+
+    /// Number of frames recorded so far.
+    pub fn get_recorded(&self) -> usize {
+        self.recorded
+    }
+
+    /// Size of the images in pixels.
+    fn get_size(&self, canvas: &Bounds) -> io::Result<(u32, u32)> {
+        let pixels = |cells: u64| {
+            cells
+                .checked_mul(self.scale as u64)
+                .and_then(|pixels| u32::try_from(pixels).ok())
+                .ok_or_else(|| io::Error::other("the image is too large"))
+        };
+        Ok((pixels(canvas.width())?, pixels(canvas.height())?))
+    }
+
+    /// Colours of the cells of a frame on the canvas, row by row.
+    fn get_cells(&self, frame: &Frame, canvas: &Bounds) -> Vec<Color> {
+        let width = canvas.width() as usize;
+        let mut cells = vec![self.background; width * canvas.height() as usize];
+        for ((x, y), glyph) in frame.glyphs.iter() {
+            let (x, y) = (frame.bounds.min_x + x as i64, frame.bounds.min_y + y as i64);
+            if canvas.contains(x, y) {
+                let index = (y - canvas.min_y) as usize * width + (x - canvas.min_x) as usize;
+                cells[index] = self.palette.get(*glyph).unwrap_or(self.background);
+            }
+        }
+        cells
+    }
+
+    /// Region of the cells that differ from the last recorded frame, relative to the canvas.
+    ///
+    /// It is the whole canvas for the first frame and a single cell if nothing changed, as every
+    /// frame has to be written to take up its frame time.
+    fn get_changes(&self, cells: &[Color], width: usize) -> Bounds {
+        if self.previous.is_empty() {
+            let mut changes = Bounds::new(0, 0);
+            changes.include(width as i64 - 1, (cells.len() / width) as i64 - 1);
+            return changes;
+        }
+        let mut changes: Option<Bounds> = None;
+        for (index, _) in cells
+            .iter()
+            .zip(&self.previous)
+            .enumerate()
+            .filter(|(_, (cell, previous))| cell != previous)
+        {
+            let (x, y) = ((index % width) as i64, (index / width) as i64);
+            match &mut changes {
+                Some(changes) => changes.include(x, y),
+                None => changes = Some(Bounds::new(x, y)),
+            }
+        }
+        changes.unwrap_or(Bounds::new(0, 0))
+    }
+
+    /// Colours of the pixels of a region of the cells, row by row.
+    fn get_pixels(&self, cells: &[Color], width: usize, region: &Bounds) -> Vec<Color> {
+        let scale = self.scale as usize;
+        let mut pixels = vec![];
+        for y in region.min_y as usize..=region.max_y as usize {
+            let row = &cells[y * width + region.min_x as usize..=y * width + region.max_x as usize];
+            for _ in 0..scale {
+                for color in row {
+                    pixels.extend(std::iter::repeat_n(*color, scale));
+                }
+            }
+        }
+        pixels
+    }
+
+    /// Creates the output for a canvas of the given size in pixels.
+    fn start(&self, width: u32, height: u32) -> io::Result<Encoder> {
+        match self.format {
+            Format::Gif => {
+                let too_large = |_| io::Error::other("the image is too large for a GIF");
+                let (width, height) = (
+                    u16::try_from(width).map_err(too_large)?,
+                    u16::try_from(height).map_err(too_large)?,
+                );
+                let mut colors = vec![self.background];
+                for color in self.palette.colors.values() {
+                    if !colors.contains(color) {
+                        colors.push(*color);
+                    }
+                }
+                if colors.len() > 256 {
+                    return Err(io::Error::other("a GIF cannot have more than 256 colours"));
+                }
+                let indices = (0..).zip(&colors).map(|(index, color)| (*color, index));
+                let out = BufWriter::new(File::create(&self.path)?);
+                let mut encoder = gif::Encoder::new(out, width, height, &get_rgb(&colors))
+                    .map_err(io::Error::other)?;
+                encoder
+                    .set_repeat(gif::Repeat::Infinite)
+                    .map_err(io::Error::other)?;
+                Ok(Encoder::Gif {
+                    encoder,
+                    indices: indices.collect(),
+                })
+            }
+            Format::Png => {
+                // The file is read back by `finish` to find where the number of frames goes.
+                let file = File::options()
+                    .read(true)
+                    .write(true)
+                    .create(true)
+                    .truncate(true)
+                    .open(&self.path)?;
+                let mut encoder =
+                    png::Encoder::new(BufWriter::new(file.try_clone()?), width, height);
+                encoder.set_color(png::ColorType::Rgb);
+                encoder.set_depth(png::BitDepth::Eight);
+                let delay = self.frame_time.as_millis().clamp(1, u16::MAX as u128) as u16;
+                // The number of frames is written by `finish`.
+                encoder
+                    .set_animated(u32::MAX, 0)
+                    .and_then(|_| encoder.set_frame_delay(delay, 1000))
+                    .map_err(io::Error::other)?;
+                let writer = encoder.write_header().map_err(io::Error::other)?;
+                Ok(Encoder::Png { writer, file })
+            }
+            Format::Frames => {
+                std::fs::create_dir_all(&self.path)?;
+                Ok(Encoder::Frames)
+            }
+        }
+    }
+
+    /// Writes the pixels of a region of the canvas, given in cells.
+    fn write(&mut self, region: &Bounds, pixels: &[Color]) -> io::Result<()> {
+        let scale = self.scale;
+        let (left, top) = (region.min_x as u32 * scale, region.min_y as u32 * scale);
+        let (width, height) = (
+            region.width() as u32 * scale,
+            region.height() as u32 * scale,
+        );
+        match self.encoder.as_mut().expect("the output has been started") {
+            Encoder::Gif { encoder, indices } => {
+                let frame = gif::Frame {
+                    left: left as u16,
+                    top: top as u16,
+                    width: width as u16,
+                    height: height as u16,
+                    // GIF delays are in hundredths of a second.
+                    delay: (self.frame_time.as_millis() / 10).clamp(1, u16::MAX as u128) as u16,
+                    dispose: gif::DisposalMethod::Keep,
+                    buffer: Cow::Owned(pixels.iter().map(|color| indices[color]).collect()),
+                    ..Default::default()
+                };
+                encoder.write_frame(&frame).map_err(io::Error::other)
+            }
+            Encoder::Png { writer, .. } => writer
+                .reset_frame_position()
+                .and_then(|_| writer.set_frame_dimension(width, height))
+                .and_then(|_| writer.set_frame_position(left, top))
+                .and_then(|_| writer.write_image_data(&get_rgb(pixels)))
+                .map_err(io::Error::other),
+            Encoder::Frames => {
+                let path = self.path.join(format!("frame{:05}.png", self.recorded));
+                write_png_image(&path, width, height, pixels)
+            }
+        }
+    }
+}
+
+/// Components of the colours, in the order of red, green and blue.
+fn get_rgb(pixels: &[Color]) -> Vec<u8> {
+    pixels
+        .iter()
+        .flat_map(|Color { r, g, b }| [*r, *g, *b])
+        .collect()
+}
+
+fn write_png_image(path: &Path, width: u32, height: u32, pixels: &[Color]) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    let mut encoder = png::Encoder::new(&mut out, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&get_rgb(pixels)))
+        .map_err(io::Error::other)?;
+    out.flush()
+}
+
+impl FrameSink for Recorder {
+    fn draw_frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let canvas = *self.canvas.get_or_insert(frame.bounds);
+        if self.encoder.is_none() {
+            let (width, height) = self.get_size(&canvas)?;
+            self.encoder = Some(self.start(width, height)?);
+        }
+        let width = canvas.width() as usize;
+        let cells = self.get_cells(frame, &canvas);
+        let region = self.get_changes(&cells, width);
+        let pixels = self.get_pixels(&cells, width, &region);
+        self.write(&region, &pixels)?;
+        // Images of a directory are written whole.
+        if self.format != Format::Frames {
+            self.previous = cells;
+        }
+        self.recorded += 1;
+        Ok(())
+    }
+
+    /// Takes the canvas if no frame has been recorded yet.
+    fn set_canvas(&mut self, canvas: Bounds) {
+        if self.encoder.is_none() {
+            self.canvas = Some(canvas);
+        }
+    }
+
+    /// Completes the output file, failing if no frame was recorded.
+    fn finish(&mut self) -> io::Result<()> {
+        match self.encoder.take() {
+            None => Err(io::Error::other("no frames were recorded")),
+            Some(Encoder::Gif { encoder, .. }) => encoder.into_inner()?.flush(),
+            Some(Encoder::Png { writer, mut file }) => {
+                // Writes the end of the image and flushes the buffered writer.
+                writer.finish().map_err(io::Error::other)?;
+                let frames = u32::try_from(self.recorded)
+                    .map_err(|_| io::Error::other("too many frames for a PNG"))?;
+                let control = png::AnimationControl {
+                    num_frames: frames,
+                    num_plays: 0,
+                };
+                let offset = find_png_chunk(&mut file, b"acTL")?;
+                file.seek(SeekFrom::Start(offset))?;
+                control.encode(&mut file).map_err(io::Error::other)?;
+                file.flush()
+            }
+            Some(Encoder::Frames) => Ok(()),
+        }
+    }
+}
+
+/// Offset of the first chunk of the given type in a PNG file.
+fn find_png_chunk(file: &mut File, chunk_type: &[u8; 4]) -> io::Result<u64> {
+    let mut offset = file.seek(SeekFrom::Start(PNG_SIGNATURE_LEN))?;
+    loop {
+        let mut header = [0; 8];
+        file.read_exact(&mut header)?;
+        if header[4..] == chunk_type[..] {
+            return Ok(offset);
+        }
+        if &header[4..] == b"IEND" {
+            let chunk_type = String::from_utf8_lossy(chunk_type);
+            return Err(io::Error::other(format!(
+                "no {chunk_type} chunk in the PNG"
+            )));
+        }
+        let length = u32::from_be_bytes([header[0], header[1], header[2], header[3]]);
+        // The data is followed by a checksum of four bytes.
+        offset = file.seek(SeekFrom::Start(offset + 8 + u64::from(length) + 4))?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Recorder;
+    use crate::grid::{Bounds, SparseGrid};
+    use crate::viz::{Color, Frame, FrameSink, Palette};
+
+    fn record(recorder: Recorder) -> Recorder {
+        let palette = Palette::new().with('#', Color::WHITE);
+        let mut recorder = recorder.palette(palette).scale(2);
+        let mut canvas = Bounds::new(0, 0);
+        canvas.include(2, 1);
+        recorder.set_canvas(canvas);
+        let mut grid = [((0, 0), '#')].into_iter().collect::<SparseGrid<_>>();
+        recorder.draw(&grid).unwrap();
+        grid.insert(2, 1, 'o');
+        grid.insert(1, 1, '#');
+        recorder.draw(&grid).unwrap();
+        recorder
+    }
+
+    #[test]
+    fn changed_regions() {
+        let mut canvas = Bounds::new(0, 0);
+        canvas.include(2, 1);
+        let mut recorder = Recorder::frames("unused")
+            .palette(Palette::new().with('#', Color::WHITE))
+            .scale(2)
+            .canvas(canvas);
+        let mut grid = [((0, 0), '#'), ((5, 5), '#')]
+            .into_iter()
+            .collect::<SparseGrid<_>>();
+        let cells = recorder.get_cells(&Frame::capture(&grid), &canvas);
+        assert_eq!(6, cells.len());
+        assert_eq!(canvas, recorder.get_changes(&cells, 3));
+        recorder.previous = cells;
+        grid.insert(1, 1, '#');
+        let cells = recorder.get_cells(&Frame::capture(&grid), &canvas);
+        let changes = recorder.get_changes(&cells, 3);
+        assert_eq!(Bounds::new(1, 1), changes);
+        assert_eq!(
+            vec![Color::WHITE; 4],
+            recorder.get_pixels(&cells, 3, &changes)
+        );
+        assert_eq!(
+            Bounds::new(0, 0),
+            recorder.get_changes(&recorder.previous, 3)
+        );
+    }
+
+    #[test]
+    fn encode_images() {
+        let dir = std::env::temp_dir().join("aoc-util-recorder");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let mut recorder = record(Recorder::new(dir.join("animation.png")).unwrap());
+        recorder.finish().unwrap();
+        let file = std::fs::File::open(dir.join("animation.png")).unwrap();
+        let mut reader = png::Decoder::new(file).read_info().unwrap();
+        let info = reader.info();
+        assert_eq!((6, 4), (info.width, info.height));
+        assert_eq!(2, info.animation_control.unwrap().num_frames);
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut buffer).unwrap();
+        reader.next_frame(&mut buffer).unwrap();
+        let control = reader.info().frame_control.unwrap();
+        assert_eq!(
+            (2, 2, 2, 2),
+            (
+                control.width,
+                control.height,
+                control.x_offset,
+                control.y_offset
+            )
+        );
+
+        let mut recorder = record(Recorder::new(dir.join("animation.gif")).unwrap());
+        recorder.finish().unwrap();
+        let file = std::fs::File::open(dir.join("animation.gif")).unwrap();
+        let mut decoder = gif::DecodeOptions::new().read_info(file).unwrap();
+        assert_eq!((6, 4), (decoder.width(), decoder.height()));
+        decoder.read_next_frame().unwrap();
+        let frame = decoder.read_next_frame().unwrap().unwrap();
+        assert_eq!(
+            (2, 2, 2, 2),
+            (frame.width, frame.height, frame.left, frame.top)
+        );
+        assert!(decoder.read_next_frame().unwrap().is_none());
+
+        let mut recorder = record(Recorder::frames(dir.join("frames")));
+        recorder.finish().unwrap();
+        assert_eq!(2, recorder.get_recorded());
+        assert!(dir.join("frames").join("frame00001.png").exists());
+
+        assert!(Recorder::new(dir.join("animation.txt")).is_err());
+        assert!(Recorder::new(dir.join("empty.gif"))
+            .unwrap()
+            .finish()
+            .is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}